@@ -0,0 +1,248 @@
+//! Recorded answers per day (`dayXY/answers.toml`) and verification of the solvers against them
+
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Once;
+
+use anyhow::{bail, Context, Result};
+use utils::{Answer, InputFile, InputKind};
+
+use crate::registry::{Day, Part};
+
+/// Recorded answers for one input file of a day
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expectation {
+    /// name of the section, also the file name without ".txt" (e.g. "test", "input")
+    pub name: String,
+    /// input file
+    pub file: PathBuf,
+    /// kind of input ("test*" sections are examples)
+    pub kind: InputKind,
+    /// expected answer of part 1 (if known)
+    pub part1: Option<Answer>,
+    /// expected answer of part 2 (if known)
+    pub part2: Option<Answer>,
+}
+
+impl Expectation {
+    /// Returns the expected answer for given part
+    pub fn expected(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+/// Result of checking one part on one input
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    /// solver returned the recorded answer
+    Pass,
+    /// solver returned a different answer than recorded
+    Changed {
+        /// recorded answer
+        expected: Answer,
+        /// answer returned by the solver
+        actual: Answer,
+    },
+    /// solver panicked
+    Failed(String),
+}
+
+/// Outcome of one part of a day on one input
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    /// number of the day
+    pub day: u8,
+    /// name of the input (section in answers.toml)
+    pub input: String,
+    /// checked part
+    pub part: Part,
+    /// outcome
+    pub outcome: Outcome,
+}
+
+/// Path of the answers file of a day
+pub fn answers_file(day: &Day) -> Result<PathBuf> {
+    Ok(day.folder()?.join("answers.toml"))
+}
+
+/// Parses the content of an answers file, input files are looked up in `folder`
+pub fn parse_answers(folder: &Path, content: &str) -> Result<Vec<Expectation>> {
+    let table: toml::Table = content.parse()?;
+    table
+        .into_iter()
+        .map(|(name, section)| {
+            let Some(section) = section.as_table() else {
+                bail!("[{name}] must be a table");
+            };
+            let input_file: InputFile = name.parse().with_context(|| format!("[{name}]"))?;
+            let answer = |key: &str| section.get(key).map(to_answer).transpose();
+            Ok(Expectation {
+                file: folder.join(format!("{name}.txt")),
+                kind: input_file.kind(),
+                part1: answer("part1").with_context(|| format!("[{name}] part1"))?,
+                part2: answer("part2").with_context(|| format!("[{name}] part2"))?,
+                name,
+            })
+        })
+        .collect()
+}
+
+fn to_answer(value: &toml::Value) -> Result<Answer> {
+    match value {
+        toml::Value::Integer(value) => Ok((*value).into()),
+        // normalize in case git checked out the file with CRLF
+        toml::Value::String(value) => Ok(value.replace("\r\n", "\n").into()),
+        _ => bail!("answer must be an integer or a string, got {value}"),
+    }
+}
+
+/// Loads the recorded answers of a day
+pub fn load(day: &Day) -> Result<Vec<Expectation>> {
+    let file = answers_file(day)?;
+    let content =
+        fs::read_to_string(&file).with_context(|| format!("reading {}", file.display()))?;
+    let folder = file.parent().unwrap_or(Path::new("."));
+    parse_answers(folder, &content).with_context(|| format!("parsing {}", file.display()))
+}
+
+/// Runs the solvers of a day on all recorded inputs and compares the answers
+pub fn verify(day: &Day, parts: &[Part]) -> Result<Vec<Check>> {
+    let mut checks = Vec::new();
+    for expectation in load(day)? {
+        let file = expectation.file.to_string_lossy();
+        for &part in parts {
+            let Some(expected) = expectation.expected(part) else {
+                continue;
+            };
+            let solver = day.solver(part);
+            let outcome = match catch_panic(|| solver(&file, expectation.kind)) {
+                Ok(Ok(actual)) if &actual == expected => Outcome::Pass,
+                Ok(Ok(actual)) => Outcome::Changed {
+                    expected: expected.clone(),
+                    actual,
+                },
+                Ok(Err(error)) => Outcome::Failed(error.to_string()),
+                Err(message) => Outcome::Failed(message),
+            };
+            checks.push(Check {
+                day: day.day,
+                input: expectation.name.clone(),
+                part,
+                outcome,
+            });
+        }
+    }
+    Ok(checks)
+}
+
+/// Runs f and returns the panic message instead of unwinding (the default panic output is suppressed)
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    silence_panics(|| catch_panic_message(f))
+}
+
+/// Number of running [`silence_panics`] calls, panics are only printed while it is 0
+static SILENCED: AtomicUsize = AtomicUsize::new(0);
+
+/// Runs f without printing panics. The panic hook is global: while any call is running the
+/// panics of all threads (e.g. the rayon workers started by f) are silenced, calls may overlap
+/// and nest.
+pub fn silence_panics<T>(f: impl FnOnce() -> T) -> T {
+    // the hook is only swapped once, swapping it per call races with overlapping calls
+    static FILTER_HOOK: Once = Once::new();
+    FILTER_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if SILENCED.load(Ordering::SeqCst) == 0 {
+                default_hook(info);
+            }
+        }));
+    });
+    SILENCED.fetch_add(1, Ordering::SeqCst);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    SILENCED.fetch_sub(1, Ordering::SeqCst);
+    result.unwrap_or_else(|payload| panic::resume_unwind(payload))
+}
+
+/// Runs f and returns the panic message instead of unwinding (panics are still printed by the hook)
+pub fn catch_panic_message<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(ToString::to_string)
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let expectations = parse_answers(
+            Path::new("day42"),
+            "[test]\npart1 = 24000\n\n[input]\npart1 = 72602\npart2 = '''\nA\r\nB'''\n",
+        )
+        .unwrap();
+        let test = expectations.iter().find(|e| e.name == "test").unwrap();
+        assert_eq!(test.file, PathBuf::from("day42/test.txt"));
+        assert_eq!(test.kind, InputKind::Example);
+        assert_eq!(test.expected(Part::One), Some(&Answer::Int(24000)));
+        assert_eq!(test.expected(Part::Two), None);
+        let input = expectations.iter().find(|e| e.name == "input").unwrap();
+        assert_eq!(input.kind, InputKind::Puzzle);
+        assert_eq!(input.part2, Some(Answer::Text("A\nB".to_string())));
+
+        assert!(parse_answers(Path::new("day42"), "[test]\npart1 = 1.5\n").is_err());
+        assert!(parse_answers(Path::new("day42"), "test = 1\n").is_err());
+        assert!(parse_answers(Path::new("day42"), "[\"../x\"]\npart1 = 1\n").is_err());
+    }
+
+    #[test]
+    fn all_answer_files_parse() {
+        for day in &crate::registry::DAYS {
+            let expectations = load(day).unwrap();
+            assert!(
+                expectations.iter().any(|e| e.name == "input"),
+                "{}",
+                day.name()
+            );
+        }
+    }
+
+    #[test]
+    fn catch_panics() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+        assert_eq!(
+            catch_panic(|| -> u8 { panic!("boom") }),
+            Err("boom".to_string())
+        );
+    }
+
+    #[test]
+    fn overlapping_silence() {
+        // calls on several threads overlap and nest, each one counts until it ends
+        let threads = (0..4)
+            .map(|thread| {
+                std::thread::spawn(move || {
+                    for _ in 0..50 {
+                        let nested = silence_panics(|| {
+                            assert!(SILENCED.load(Ordering::SeqCst) >= 1);
+                            catch_panic(|| -> u8 { panic!("thread {thread}") })
+                        });
+                        assert_eq!(nested, Err(format!("thread {thread}")));
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! Runs a day on every input file of a folder in parallel, e.g. to check a solution against the
+//! puzzles of many users
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use rayon::prelude::*;
+use utils::InputKind;
+
+use crate::answers::silence_panics;
+use crate::registry::{Day, Part};
+use crate::summary::{self, Entry};
+
+/// Answers of all parts for one input file
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileResult {
+    /// input file
+    pub file: PathBuf,
+    /// kind of the input (files starting with "test" are examples)
+    pub kind: InputKind,
+    /// answer and time per part
+    pub entries: Vec<Entry>,
+}
+
+/// All input files of a folder (`input*.txt` and `test*.txt`, see [`kind_of`]), sorted by name
+pub fn input_files(folder: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = fs::read_dir(folder)
+        .with_context(|| format!("reading {}", folder.display()))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(|| format!("reading {}", folder.display()))?;
+    files.retain(|file| file.is_file() && kind_of(file).is_some());
+    files.sort();
+    if files.is_empty() {
+        bail!("no input*.txt or test*.txt inputs in {}", folder.display());
+    }
+    Ok(files)
+}
+
+/// Kind of an input file, same rule as for the sections in answers.toml: `test*.txt` are
+/// examples, `input*.txt` are puzzles. The name may only go on with digits (`test2.txt`) or a
+/// suffix after '_' or '-' (`input_alice.txt`), other files like notes or copies are no inputs.
+pub fn kind_of(file: &Path) -> Option<InputKind> {
+    if file.extension()? != "txt" {
+        return None;
+    }
+    let stem = file.file_stem()?.to_str()?;
+    let (kind, rest) = if let Some(rest) = stem.strip_prefix("test") {
+        (InputKind::Example, rest)
+    } else {
+        (InputKind::Puzzle, stem.strip_prefix("input")?)
+    };
+    let is_suffix = rest.chars().all(|ch| ch.is_ascii_digit())
+        || (rest.starts_with(['_', '-'])
+            && rest[1..]
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '-'));
+    is_suffix.then_some(kind)
+}
+
+/// Solves the given parts for all files in parallel, errors and panics are caught per input
+pub fn run(day: &Day, files: &[PathBuf], parts: &[Part]) -> Vec<FileResult> {
+    silence_panics(|| {
+        files
+            .par_iter()
+            .map(|file| {
+                let kind = kind_of(file).unwrap_or(InputKind::Puzzle);
+                let input = file.to_string_lossy();
+                FileResult {
+                    file: file.clone(),
+                    kind,
+                    entries: parts
+                        .iter()
+                        .map(|&part| summary::run_part(day, part, &input, kind))
+                        .collect(),
+                }
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+    use utils::Answer;
+
+    #[test]
+    fn run_folder() {
+        let folder = std::env::temp_dir().join(format!("aoc_batch_{}", std::process::id()));
+        fs::create_dir_all(&folder).unwrap();
+        fs::copy("../day01/test.txt", folder.join("test.txt")).unwrap();
+        fs::copy("../day01/input.txt", folder.join("input_other.txt")).unwrap();
+        fs::write(folder.join("test_broken.txt"), "1\nx\n").unwrap();
+        // stray files next to the inputs are skipped
+        for stray in [
+            "notes.md",
+            "ideas.txt",
+            "test copy.txt",
+            "testing.txt",
+            "input.txt.bak",
+        ] {
+            fs::write(folder.join(stray), "ignored").unwrap();
+        }
+
+        let files = input_files(&folder).unwrap();
+        let names = files
+            .iter()
+            .map(|file| file.file_name().unwrap().to_string_lossy())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["input_other.txt", "test_broken.txt", "test.txt"]);
+        assert_eq!(kind_of(&files[1]), Some(InputKind::Example));
+        assert_eq!(
+            kind_of(Path::new("day23/test2.txt")),
+            Some(InputKind::Example)
+        );
+        assert_eq!(kind_of(Path::new("input-bob.txt")), Some(InputKind::Puzzle));
+        assert_eq!(kind_of(Path::new("day23/test copy.txt")), None);
+
+        let results = run(registry::get(1).unwrap(), &files, &[Part::One]);
+        assert_eq!(results[0].kind, InputKind::Puzzle);
+        assert_eq!(results[0].entries[0].answer, Ok(Answer::Int(72602)));
+        assert!(results[1].entries[0].answer.is_err());
+        assert_eq!(results[2].entries[0].answer, Ok(Answer::Int(24000)));
+
+        fs::remove_dir_all(&folder).unwrap();
+        assert!(input_files(&folder).is_err());
+    }
+}
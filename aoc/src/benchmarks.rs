@@ -0,0 +1,131 @@
+//! Benchmarks of the days with the [`utils::bench`] harness and their baselines (`dayXY/benchmark.json`)
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use utils::bench::{self, Baseline, BenchConfig, Comparison, Measurement};
+use utils::InputKind;
+
+use crate::registry::{Day, Part};
+
+/// Benchmark of one part of a day on one input
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartBench {
+    /// benchmarked part
+    pub part: Part,
+    /// name in the baseline (input file name and part, e.g. "input part1")
+    pub name: String,
+    /// statistics of the run
+    pub measurement: Measurement,
+    /// comparison with the saved baseline (if there is one for this input and part)
+    pub comparison: Option<Comparison>,
+}
+
+/// Path of the benchmark baseline of a day
+pub fn baseline_file(day: &Day) -> Result<PathBuf> {
+    Ok(day.folder()?.join("benchmark.json"))
+}
+
+/// Loads the baseline of a day (empty if none was saved yet)
+pub fn load_baseline(day: &Day) -> Result<Baseline> {
+    let file = baseline_file(day)?;
+    if !file.exists() {
+        return Ok(Baseline::default());
+    }
+    Baseline::load(&file).with_context(|| format!("loading {}", file.display()))
+}
+
+/// Name of a benchmark in the baseline
+pub fn benchmark_name(input: &str, part: Part) -> String {
+    let input = Path::new(input)
+        .file_stem()
+        .map_or_else(|| input.into(), |stem| stem.to_string_lossy());
+    format!("{input} part{part}")
+}
+
+/// Benchmarks the given parts of a day and compares them with `baseline`
+pub fn run(
+    day: &Day,
+    input: &str,
+    kind: InputKind,
+    parts: &[Part],
+    config: &BenchConfig,
+    baseline: &Baseline,
+) -> Result<Vec<PartBench>> {
+    parts
+        .iter()
+        .map(|&part| {
+            let solver = day.solver(part);
+            // a failing solver is reported once instead of being measured
+            solver(input, kind).with_context(|| format!("Day {:02} Part {part}", day.day))?;
+            let measurement = bench::measure(config, || solver(input, kind));
+            let name = benchmark_name(input, part);
+            Ok(PartBench {
+                part,
+                comparison: baseline.compare(&name, &measurement),
+                name,
+                measurement,
+            })
+        })
+        .collect()
+}
+
+/// Stores the results in the baseline of the day (other inputs and parts are kept)
+pub fn save_baseline(day: &Day, mut baseline: Baseline, results: &[PartBench]) -> Result<()> {
+    for result in results {
+        baseline
+            .measurements
+            .insert(result.name.clone(), result.measurement.clone());
+    }
+    let file = baseline_file(day)?;
+    baseline
+        .save(&file)
+        .with_context(|| format!("saving {}", file.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn names() {
+        assert_eq!(benchmark_name("day01/input.txt", Part::One), "input part1");
+        assert_eq!(benchmark_name("test2.txt", Part::Two), "test2 part2");
+    }
+
+    #[test]
+    fn bench_example() {
+        let config = BenchConfig {
+            warm_up: std::time::Duration::ZERO,
+            measurement_time: std::time::Duration::ZERO,
+            min_samples: 2,
+            max_samples: 2,
+        };
+        let day = registry::get(5).unwrap();
+        let results = run(
+            day,
+            "../day05/test.txt",
+            InputKind::Example,
+            &Part::ALL,
+            &config,
+            &Baseline::default(),
+        )
+        .unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[1].name, "test part2");
+        assert_eq!(results[1].measurement.total.samples, 2);
+        assert!(results[0].measurement.parse.is_some());
+        assert!(results[0].comparison.is_none());
+
+        assert!(run(
+            day,
+            "../day05/missing.txt",
+            InputKind::Example,
+            &Part::ALL,
+            &config,
+            &Baseline::default(),
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,17 @@
+//! Registry of all days, used by the `aoc` runner
+
+#![deny(clippy::all, clippy::pedantic)]
+#![allow(
+    clippy::enum_glob_use,
+    clippy::many_single_char_names,
+    clippy::must_use_candidate
+)]
+#![deny(missing_docs)]
+#![allow(clippy::missing_panics_doc, clippy::missing_errors_doc)]
+#![allow(clippy::doc_markdown)]
+
+pub mod answers;
+pub mod batch;
+pub mod benchmarks;
+pub mod registry;
+pub mod summary;
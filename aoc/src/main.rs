@@ -0,0 +1,348 @@
+//! Runs one or all days: `aoc 12`, `aoc 12 --part 2`, `aoc 12 --example`, `aoc 12 --input my.txt`, `aoc all`
+//! verifies them against the recorded answers: `aoc all --verify`
+//! benchmarks them against a saved baseline: `aoc 12 --bench [--save-baseline]`
+//! prints a summary table of all answers and times: `aoc all --summary [--format json]`
+//! and runs a day on every input of a folder: `aoc 22 --batch [folder]`
+
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::process::ExitCode;
+
+use aoc::answers::{self, Outcome};
+use aoc::batch;
+use aoc::benchmarks;
+use aoc::registry::{self, Day, Part};
+use aoc::summary::{self, Summary};
+use clap::{Parser, ValueEnum};
+use utils::bench::BenchConfig;
+use utils::{InputFile, InputKind, InputResult};
+
+/// Advent of Code 2022 runner
+#[derive(Parser, Debug)]
+#[command(version, about)]
+#[allow(clippy::struct_excessive_bools)] // command line flags
+struct Args {
+    /// Day to run (1..=25) or "all"
+    day: String,
+
+    /// Only run the given part (1 or 2)
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Input file to use instead of dayXY/input.txt (only for a single day)
+    #[arg(short, long)]
+    input: Option<String>,
+
+    /// Use the example (dayXY/test.txt) and the example parameters of the day
+    #[arg(short, long)]
+    example: bool,
+
+    /// Use dayXY/<FILE>.txt, e.g. "test2" (files starting with "test" use the example parameters)
+    #[arg(short, long, conflicts_with_all = ["input", "example"])]
+    file: Option<InputFile>,
+
+    /// Check the answers of all inputs listed in dayXY/answers.toml
+    #[arg(long, conflicts_with_all = ["input", "example", "file"])]
+    verify: bool,
+
+    /// Benchmark (warm-up, repeated samples) and compare with the baseline in dayXY/benchmark.json
+    #[arg(long, conflicts_with = "verify")]
+    bench: bool,
+
+    /// Save the benchmark results as new baseline
+    #[arg(long, requires = "bench")]
+    save_baseline: bool,
+
+    /// Slow down of the median (in percent) that is flagged as regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+
+    /// Measurement time per part in seconds
+    #[arg(long, default_value_t = 3.0)]
+    bench_time: f64,
+
+    /// Print one table with answer, time and share of the total time of every part (slowest first)
+    #[arg(long, conflicts_with_all = ["verify", "bench"])]
+    summary: bool,
+
+    /// Output format of the summary
+    #[arg(long, value_enum, default_value_t = Format::Markdown, requires = "summary")]
+    format: Format,
+
+    /// Write the summary to this file instead of stdout (some days print debug output)
+    #[arg(long, requires = "summary")]
+    output: Option<String>,
+
+    /// Run on every input*.txt and test*.txt in the folder in parallel (default: folder of the day)
+    #[arg(long, value_name = "FOLDER", conflicts_with_all = ["input", "example", "file", "verify", "bench", "summary"])]
+    #[allow(clippy::option_option)] // clap: flag with optional value
+    batch: Option<Option<String>>,
+}
+
+/// Output format of the summary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Markdown,
+    Json,
+}
+
+impl Args {
+    /// Input file and kind to use for given day,
+    /// inputs are looked up in the folders of the days (root can be set with `AOC_INPUTS`)
+    fn input(&self, day: &Day) -> InputResult<(String, InputKind)> {
+        let kind = if self.example {
+            InputKind::Example
+        } else {
+            InputKind::Puzzle
+        };
+        match (&self.input, &self.file) {
+            (Some(input), _) => Ok((input.clone(), kind)),
+            (None, Some(file)) => Ok((day.input(file)?, file.kind())),
+            (None, None) => Ok((day.input(&kind.into())?, kind)),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+/// The main function runs the selected days and parts
+/// AOC
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let days: Vec<&Day> = if args.day == "all" {
+        if args.input.is_some() || matches!(args.batch, Some(Some(_))) {
+            eprintln!("--input and --batch <FOLDER> can only be used for a single day");
+            return ExitCode::FAILURE;
+        }
+        registry::DAYS.iter().collect()
+    } else if let Some(day) = args.day.parse().ok().and_then(registry::get) {
+        vec![day]
+    } else {
+        eprintln!("unknown day '{}', expected 1..=25 or 'all'", args.day);
+        return ExitCode::FAILURE;
+    };
+
+    let parts = match args.part {
+        Some(1) => vec![Part::One],
+        Some(_) => vec![Part::Two],
+        None => Part::ALL.to_vec(),
+    };
+
+    if args.verify {
+        verify(&days, &parts)
+    } else if args.bench {
+        bench(&args, &days, &parts)
+    } else if args.summary {
+        summary(&args, &days, &parts)
+    } else if let Some(folder) = &args.batch {
+        batch(folder.as_deref(), &days, &parts)
+    } else {
+        run(&args, &days, &parts)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+fn run(args: &Args, days: &[&Day], parts: &[Part]) -> ExitCode {
+    let mut failed = false;
+    for day in days {
+        let (input, kind) = match args.input(day) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("Day {:02}: {error}", day.day);
+                failed = true;
+                continue;
+            }
+        };
+        for &part in parts {
+            let solver = day.solver(part);
+            let title = format!("Day {:02} Part {part}", day.day);
+            let (result, timing) = utils::bench::time(|| solver(&input, kind));
+            let answer = result.map_or_else(
+                |error| {
+                    failed = true;
+                    format!("error: {error}")
+                },
+                |answer| answer.to_string(),
+            );
+            println!("{title} result: {answer} ({timing})");
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn verify(days: &[&Day], parts: &[Part]) -> ExitCode {
+    let (mut passed, mut changed, mut failed) = (0, 0, 0);
+    for day in days {
+        match answers::verify(day, parts) {
+            Ok(checks) => {
+                for check in checks {
+                    let title = format!(
+                        "Day {:02} {:<6} Part {}",
+                        check.day, check.input, check.part
+                    );
+                    match check.outcome {
+                        Outcome::Pass => {
+                            passed += 1;
+                            println!("{title}: pass");
+                        }
+                        Outcome::Changed { expected, actual } => {
+                            changed += 1;
+                            println!("{title}: CHANGED (expected {expected}, got {actual})");
+                        }
+                        Outcome::Failed(message) => {
+                            failed += 1;
+                            println!("{title}: FAILED ({message})");
+                        }
+                    }
+                }
+            }
+            Err(error) => {
+                failed += 1;
+                println!("Day {:02}: FAILED ({error:#})", day.day);
+            }
+        }
+    }
+    println!("{passed} passed, {changed} changed, {failed} failed");
+
+    if changed + failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn bench(args: &Args, days: &[&Day], parts: &[Part]) -> ExitCode {
+    let config = BenchConfig {
+        measurement_time: std::time::Duration::from_secs_f64(args.bench_time),
+        ..BenchConfig::default()
+    };
+    let (mut regressions, mut failed) = (0, 0);
+    for day in days {
+        let result = benchmarks::load_baseline(day).and_then(|baseline| {
+            let (input, kind) = args.input(day)?;
+            let results = benchmarks::run(day, &input, kind, parts, &config, &baseline)?;
+            for result in &results {
+                print!(
+                    "Day {:02} Part {}: {}",
+                    day.day, result.part, result.measurement
+                );
+                if let Some(comparison) = &result.comparison {
+                    print!(", {comparison}");
+                    if comparison.is_regression(args.threshold / 100.0) {
+                        regressions += 1;
+                        print!(" REGRESSION");
+                    }
+                }
+                println!();
+            }
+            if args.save_baseline {
+                benchmarks::save_baseline(day, baseline, &results)?;
+            }
+            Ok(())
+        });
+        if let Err(error) = result {
+            failed += 1;
+            println!("Day {:02}: FAILED ({error:#})", day.day);
+        }
+    }
+    if regressions + failed > 0 {
+        println!("{regressions} regressions, {failed} failed");
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn summary(args: &Args, days: &[&Day], parts: &[Part]) -> ExitCode {
+    let mut entries = Vec::new();
+    for day in days {
+        let input = args.input(day);
+        for &part in parts {
+            let entry = match &input {
+                Ok((input, kind)) => {
+                    answers::silence_panics(|| summary::run_part(day, part, input, *kind))
+                }
+                Err(error) => summary::Entry {
+                    day: day.day,
+                    part,
+                    answer: Err(error.to_string()),
+                    time: std::time::Duration::ZERO,
+                },
+            };
+            // progress goes to stderr, stdout only gets the report
+            eprintln!("Day {:02} Part {part}: {:.2?}", day.day, entry.time);
+            entries.push(entry);
+        }
+    }
+    let summary = Summary::new(entries);
+    let report = match args.format {
+        Format::Markdown => summary.to_markdown(),
+        Format::Json => summary.to_json() + "\n",
+    };
+    if let Some(output) = &args.output {
+        if let Err(error) = std::fs::write(output, report) {
+            eprintln!("{output}: {error}");
+            return ExitCode::FAILURE;
+        }
+    } else {
+        print!("{report}");
+    }
+
+    if summary.entries.iter().all(|entry| entry.answer.is_ok()) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn batch(folder: Option<&str>, days: &[&Day], parts: &[Part]) -> ExitCode {
+    let (mut inputs, mut failed) = (0, 0);
+    for day in days {
+        let files = match folder {
+            Some(folder) => batch::input_files(std::path::Path::new(folder)),
+            None => day
+                .folder()
+                .map_err(Into::into)
+                .and_then(|folder| batch::input_files(&folder)),
+        };
+        let files = match files {
+            Ok(files) => files,
+            Err(error) => {
+                failed += 1;
+                println!("Day {:02}: FAILED ({error:#})", day.day);
+                continue;
+            }
+        };
+        for result in batch::run(day, &files, parts) {
+            inputs += 1;
+            let name = result
+                .file
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy();
+            for entry in result.entries {
+                let title = format!("Day {:02} {name} Part {}", day.day, entry.part);
+                match entry.answer {
+                    Ok(answer) => println!("{title}: {answer} ({:.2?})", entry.time),
+                    Err(message) => {
+                        failed += 1;
+                        println!("{title}: FAILED ({message}) ({:.2?})", entry.time);
+                    }
+                }
+            }
+        }
+    }
+    println!("{inputs} inputs, {failed} failed");
+
+    if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,134 @@
+//! Maps every day to its [`Solution`] implementation
+
+use std::fmt::Display;
+use std::path::PathBuf;
+
+use utils::{Answer, InputFile, InputKind, InputResolver, InputResult, Solution};
+
+/// Solver for one part: gets the input file name and the kind of input (selects the day parameters)
+pub type Solver = fn(&str, InputKind) -> InputResult<Answer>;
+
+/// Part of a day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    /// Part 1
+    One = 1,
+    /// Part 2
+    Two = 2,
+}
+
+impl Part {
+    /// Both parts in order
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", *self as u8)
+    }
+}
+
+/// A registered day
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    /// number of the day (1..=25)
+    pub day: u8,
+    /// solver for part 1
+    pub part1: Solver,
+    /// solver for part 2
+    pub part2: Solver,
+}
+
+impl Day {
+    /// Creates the registry entry for a [`Solution`]
+    pub const fn of<S: Solution>() -> Self {
+        Day {
+            day: S::DAY,
+            part1: |file_name, kind| S::part1(file_name, &S::params(kind)),
+            part2: |file_name, kind| S::part2(file_name, &S::params(kind)),
+        }
+    }
+
+    /// Returns the solver for given part
+    pub fn solver(&self, part: Part) -> Solver {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        }
+    }
+
+    /// Name of the crate / folder of the day (e.g. "day01")
+    pub fn name(&self) -> String {
+        format!("day{:02}", self.day)
+    }
+
+    /// Path of an input file of the day (see [`InputResolver`])
+    pub fn input(&self, file: &InputFile) -> InputResult<String> {
+        utils::input_file(self.day, file)
+    }
+
+    /// Folder of the day with its inputs, answers and benchmarks (see [`InputResolver`])
+    pub fn folder(&self) -> InputResult<PathBuf> {
+        InputResolver::from_env().resolve_folder(self.day)
+    }
+}
+
+/// All days in order
+pub static DAYS: [Day; 25] = [
+    Day::of::<day01::Day01>(),
+    Day::of::<day02::Day02>(),
+    Day::of::<day03::Day03>(),
+    Day::of::<day04::Day04>(),
+    Day::of::<day05::Day05>(),
+    Day::of::<day06::Day06>(),
+    Day::of::<day07::Day07>(),
+    Day::of::<day08::Day08>(),
+    Day::of::<day09::Day09>(),
+    Day::of::<day10::Day10>(),
+    Day::of::<day11::Day11>(),
+    Day::of::<day12::Day12>(),
+    Day::of::<day13::Day13>(),
+    Day::of::<day14::Day14>(),
+    Day::of::<day15::Day15>(),
+    Day::of::<day16::Day16>(),
+    Day::of::<day17::Day17>(),
+    Day::of::<day18::Day18>(),
+    Day::of::<day19::Day19>(),
+    Day::of::<day20::Day20>(),
+    Day::of::<day21::Day21>(),
+    Day::of::<day22::Day22>(),
+    Day::of::<day23::Day23>(),
+    Day::of::<day24::Day24>(),
+    Day::of::<day25::Day25>(),
+];
+
+/// Returns the registered day (1..=25)
+pub fn get(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_days_registered() {
+        assert!((1..=25).all(|day| get(day).is_some_and(|d| d.day == day)));
+        assert!(get(0).is_none());
+        assert!(get(26).is_none());
+        assert!(get(7)
+            .unwrap()
+            .input(&InputFile::Input)
+            .unwrap()
+            .ends_with("day07/input.txt"));
+    }
+
+    #[test]
+    fn example_params_are_used() {
+        let day15 = get(15).unwrap();
+        assert_eq!(
+            (day15.part1)("../day15/test.txt", InputKind::Example).unwrap(),
+            Answer::Int(26)
+        );
+    }
+}
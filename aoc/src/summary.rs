@@ -0,0 +1,181 @@
+//! Year summary: answer and wall time of every part in one table, sorted by time
+
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+use serde_json::json;
+use utils::{Answer, InputKind};
+
+use crate::answers::catch_panic_message;
+use crate::registry::{Day, Part};
+
+/// Answer and wall time of one part
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    /// number of the day
+    pub day: u8,
+    /// solved part
+    pub part: Part,
+    /// answer or the error / panic message
+    pub answer: Result<Answer, String>,
+    /// wall time of the solver (including parsing)
+    pub time: Duration,
+}
+
+/// All entries of a run, sorted by time (slowest first)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Summary {
+    /// entries sorted by time
+    pub entries: Vec<Entry>,
+}
+
+impl Summary {
+    /// Creates a summary of given entries
+    pub fn new(mut entries: Vec<Entry>) -> Self {
+        entries.sort_by(|a, b| {
+            b.time
+                .cmp(&a.time)
+                .then((a.day, a.part).cmp(&(b.day, b.part)))
+        });
+        Summary { entries }
+    }
+
+    /// Sum of all times
+    pub fn total(&self) -> Duration {
+        self.entries.iter().map(|entry| entry.time).sum()
+    }
+
+    /// Share of the total time (0.0..=1.0)
+    pub fn share(&self, entry: &Entry) -> f64 {
+        let total = self.total().as_secs_f64();
+        if total > 0.0 {
+            entry.time.as_secs_f64() / total
+        } else {
+            0.0
+        }
+    }
+
+    /// Renders the summary as Markdown table with a total row
+    pub fn to_markdown(&self) -> String {
+        let mut table = String::from(
+            "| Day | Part | Answer | Time | Share |\n|----:|-----:|--------|-----:|------:|\n",
+        );
+        for entry in &self.entries {
+            let answer = match &entry.answer {
+                Ok(answer) => answer.to_string(),
+                Err(message) => format!("FAILED: {message}"),
+            };
+            writeln!(
+                table,
+                "| {} | {} | {} | {:.2?} | {:.1}% |",
+                entry.day,
+                entry.part,
+                answer.replace('|', "\\|").replace('\n', "<br>"),
+                entry.time,
+                self.share(entry) * 100.0
+            )
+            .unwrap();
+        }
+        writeln!(table, "| **Total** | | | {:.2?} | 100.0% |", self.total()).unwrap();
+        table
+    }
+
+    /// Renders the summary as JSON (times in milliseconds)
+    pub fn to_json(&self) -> String {
+        let entries = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let (answer, error) = match &entry.answer {
+                    Ok(answer) => (Some(answer.to_string()), None),
+                    Err(message) => (None, Some(message)),
+                };
+                json!({
+                    "day": entry.day,
+                    "part": entry.part as u8,
+                    "answer": answer,
+                    "error": error,
+                    "time_ms": entry.time.as_secs_f64() * 1000.0,
+                    "share": self.share(entry),
+                })
+            })
+            .collect::<Vec<_>>();
+        let summary = json!({
+            "total_ms": self.total().as_secs_f64() * 1000.0,
+            "entries": entries,
+        });
+        serde_json::to_string_pretty(&summary).expect("summary is serializable")
+    }
+}
+
+/// Runs one part once and measures it, errors and panics become the answer
+/// (the panic output can be suppressed with [`silence_panics`](crate::answers::silence_panics))
+pub fn run_part(day: &Day, part: Part, input: &str, kind: InputKind) -> Entry {
+    let solver = day.solver(part);
+    let start = Instant::now();
+    let answer = catch_panic_message(|| solver(input, kind))
+        .and_then(|answer| answer.map_err(|error| error.to_string()));
+    Entry {
+        day: day.day,
+        part,
+        answer,
+        time: start.elapsed(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    fn entry(day: u8, part: Part, answer: Result<Answer, String>, millis: u64) -> Entry {
+        Entry {
+            day,
+            part,
+            answer,
+            time: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn markdown() {
+        let summary = Summary::new(vec![
+            entry(1, Part::One, Ok(Answer::Int(42)), 10),
+            entry(10, Part::Two, Ok("#.\n.#".into()), 30),
+            entry(22, Part::Two, Err("boom".into()), 0),
+        ]);
+        assert_eq!(summary.total(), Duration::from_millis(40));
+        assert_eq!(
+            summary.to_markdown(),
+            "| Day | Part | Answer | Time | Share |\n\
+             |----:|-----:|--------|-----:|------:|\n\
+             | 10 | 2 | #.<br>.# | 30.00ms | 75.0% |\n\
+             | 1 | 1 | 42 | 10.00ms | 25.0% |\n\
+             | 22 | 2 | FAILED: boom | 0.00ns | 0.0% |\n\
+             | **Total** | | | 40.00ms | 100.0% |\n"
+        );
+    }
+
+    #[test]
+    fn json() {
+        let summary = Summary::new(vec![
+            entry(2, Part::One, Ok(Answer::Int(15)), 3),
+            entry(2, Part::Two, Err("boom".into()), 1),
+        ]);
+        let json: serde_json::Value = serde_json::from_str(&summary.to_json()).unwrap();
+        assert_eq!(json["total_ms"], 4.0);
+        assert_eq!(json["entries"][0]["answer"], "15");
+        assert_eq!(json["entries"][0]["share"], 0.75);
+        assert_eq!(json["entries"][1]["error"], "boom");
+        assert!(json["entries"][1]["answer"].is_null());
+    }
+
+    #[test]
+    fn run_example() {
+        let day = registry::get(2).unwrap();
+        let entry = run_part(day, Part::One, "../day02/test.txt", InputKind::Example);
+        assert_eq!(entry.answer, Ok(Answer::Int(15)));
+        let entry = run_part(day, Part::One, "../day02/missing.txt", InputKind::Example);
+        assert!(entry.answer.is_err());
+    }
+}
@@ -0,0 +1,98 @@
+//#![allow(unused_imports)]
+//#![allow(dead_code)]
+//#![allow(unused_must_use)]
+#![deny(clippy::all, clippy::pedantic)]
+#![allow(
+    clippy::enum_glob_use,
+    clippy::many_single_char_names,
+    clippy::must_use_candidate
+)]
+#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::doc_markdown)]
+#![allow(clippy::unreadable_literal)]
+
+//#![feature(generators, generator_trait)]
+//#![feature(drain_filter)]
+//#![feature(const_option)]
+//#![feature(type_alias_impl_trait)]
+//#![feature(hash_drain_filter)]
+
+use std::cmp::Reverse;
+
+//use grid::grid_array::*;
+//#use grid::grid_iteration::*;
+//use grid::grid_types::*;
+use itertools::Itertools;
+
+use utils::{Answer, InputResult, Solution};
+
+////////////////////////////////////////////////////////////////////////////////////
+
+pub fn solve_part1(file_name: &str) -> usize {
+    utils::bench::parse_phase(|| parse(file_name))
+        .iter()
+        .map(|calories| calories.iter().sum())
+        .max()
+        .unwrap()
+}
+
+pub fn solve_part2(file_name: &str) -> usize {
+    utils::bench::parse_phase(|| parse(file_name))
+        .iter()
+        .map(|calories| calories.iter().sum::<usize>())
+        .map(Reverse) // we want the largest but we only have k_smallest
+        .k_smallest(3)
+        .map(utils::unreverse) // Since elements are Reverse(items) we have to take .0
+        .sum()
+}
+
+/// Day 1 in the common [`Solution`] format
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Params = ();
+
+    fn part1(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part1(file_name).into())
+    }
+
+    fn part2(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part2(file_name).into())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+/// calories of the items of every elf
+fn parse(file_name: &str) -> Vec<Vec<usize>> {
+    utils::file_to_chunks(file_name)
+        .map(|chunk| chunk.lines().map(utils::str_to::<usize>).collect())
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve_part1("test.txt"), 24000);
+    }
+
+    #[test]
+    fn verify1() {
+        assert_eq!(solve_part1("input.txt"), 72602);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve_part2("test.txt"), 45000);
+    }
+
+    #[test]
+    fn verify2() {
+        assert_eq!(solve_part2("input.txt"), 207410);
+    }
+}
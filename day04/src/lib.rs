@@ -0,0 +1,115 @@
+//#![allow(unused_imports)]
+//#![allow(dead_code)]
+//#![allow(unused_must_use)]
+#![deny(clippy::all, clippy::pedantic)]
+#![allow(
+    clippy::enum_glob_use,
+    clippy::many_single_char_names,
+    clippy::must_use_candidate
+)]
+#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::doc_markdown)]
+
+use itertools::Itertools;
+
+use utils::{Answer, InputResult, Solution};
+
+////////////////////////////////////////////////////////////////////////////////////
+
+pub fn solve_part1(file_name: &str) -> usize {
+    utils::bench::parse_phase(|| parse_input(file_name))
+        .filter(|(interval1, interval2)| {
+            is_fully_contained(interval1, interval2) || is_fully_contained(interval2, interval1)
+        })
+        .count()
+}
+
+pub fn solve_part2(file_name: &str) -> usize {
+    utils::bench::parse_phase(|| parse_input(file_name))
+        .filter(|(interval1, interval2)| {
+            is_fully_contained(interval1, interval2) || overlaps(interval1, interval2)
+        })
+        .count()
+}
+
+/// Day 4 in the common [`Solution`] format
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Params = ();
+
+    fn part1(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part1(file_name).into())
+    }
+
+    fn part2(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part2(file_name).into())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+fn parse_input(file_name: &str) -> impl Iterator<Item = ((usize, usize), (usize, usize))> + '_ {
+    utils::file_to_string(file_name)
+        .replace("\r\n", "\n")
+        .split('\n')
+        .map(|line| {
+            line.split(',')
+                .map(|interval| {
+                    interval
+                        .split('-')
+                        .map(utils::str_to::<usize>)
+                        .collect_tuple::<(_, _)>()
+                        .unwrap()
+                })
+                .collect_tuple::<((usize, usize), (usize, usize))>()
+                .unwrap()
+        })
+        .collect_vec()
+        .into_iter()
+}
+
+fn is_fully_contained(interval1: &(usize, usize), interval2: &(usize, usize)) -> bool {
+    let (interval1, interval2) = if interval1.0 <= interval2.0 {
+        (interval1, interval2)
+    } else {
+        (interval2, interval1)
+    };
+    interval1.0 <= interval2.0 && interval2.1 <= interval1.1
+}
+
+fn overlaps(interval1: &(usize, usize), interval2: &(usize, usize)) -> bool {
+    let (interval1, interval2) = if interval1.0 <= interval2.0 {
+        (interval1, interval2)
+    } else {
+        (interval2, interval1)
+    };
+    interval1.0 <= interval2.0 && interval2.0 <= interval1.1 && interval1.1 <= interval2.1
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve_part1("test.txt"), 2);
+    }
+
+    #[test]
+    fn verify1() {
+        assert_eq!(solve_part1("input.txt"), 471);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve_part2("test.txt"), 4);
+    }
+
+    #[test]
+    fn verify2() {
+        assert_eq!(solve_part2("input.txt"), 888);
+    }
+}
@@ -0,0 +1,175 @@
+//#![allow(unused_imports)]
+//#![allow(dead_code)]
+//#![allow(unused_must_use)]
+#![deny(clippy::all, clippy::pedantic)]
+#![allow(
+    clippy::enum_glob_use,
+    clippy::many_single_char_names,
+    clippy::must_use_candidate
+)]
+#![allow(clippy::missing_panics_doc, clippy::missing_errors_doc)]
+#![allow(clippy::doc_markdown)]
+
+use std::collections::VecDeque;
+
+use itertools::Itertools;
+
+use utils::{Answer, InputError, InputResult, Solution};
+
+////////////////////////////////////////////////////////////////////////////////////
+
+pub fn solve_part1(file_name: &str) -> InputResult<String> {
+    solve(file_name, true)
+}
+
+pub fn solve_part2(file_name: &str) -> InputResult<String> {
+    solve(file_name, false)
+}
+
+/// Day 5 in the common [`Solution`] format
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Params = ();
+
+    fn part1(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        solve_part1(file_name).map(Into::into)
+    }
+
+    fn part2(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        solve_part2(file_name).map(Into::into)
+    }
+}
+
+fn solve(file_name: &str, part1: bool) -> InputResult<String> {
+    let (mut stack, moves) = utils::bench::parse_phase(|| parse(file_name))?;
+    for (amount, from, to) in moves {
+        let mut from_stack_values = stack[from - 1].drain(..amount).collect_vec();
+        if !part1 {
+            from_stack_values.reverse();
+        }
+
+        for elem in from_stack_values {
+            stack[to - 1].push_front(elem);
+        }
+    }
+    Ok(stack.iter().map(|x| x.front().unwrap()).collect::<String>())
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+type GameInfo = (Vec<VecDeque<char>>, Vec<(usize, usize, usize)>);
+
+fn parse(file_name: &str) -> InputResult<GameInfo> {
+    let input = utils::try_file_to_string(file_name)?.replace("\r\n", "\n");
+    let (first_part, moves_part) = input.split_once("\n\n").ok_or_else(|| {
+        InputError::parse("missing empty line after the stacks", "").in_file(file_name)
+    })?;
+    let stack = parse_filled_stacks(first_part);
+    let moves =
+        parse_moves(&input, moves_part, stack.len()).map_err(|error| error.in_file(file_name))?;
+    Ok((stack, moves))
+}
+
+/// Parses "move <amount> from <from> to <to>" lines, `moves_part` must be a slice of `input` (used for the error location)
+fn parse_moves(
+    input: &str,
+    moves_part: &str,
+    amount_of_stacks: usize,
+) -> InputResult<Vec<(usize, usize, usize)>> {
+    let re =
+        regex::Regex::new(r"^move (?P<amount>\d+) from (?P<from>\d+) to (?P<to>\d+)$").unwrap();
+    moves_part
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let captures = re.captures(line).ok_or_else(|| {
+                InputError::parse("expected \"move <amount> from <from> to <to>\"", line)
+                    .located_in(input, line)
+            })?;
+            let (amount, from, to) = captures
+                .iter()
+                .skip(1) // first result is full group
+                .map(|cap| {
+                    let cap = cap.unwrap().as_str();
+                    utils::try_str_to::<usize>(cap).map_err(|error| error.located_in(input, cap))
+                })
+                .collect::<InputResult<Vec<_>>>()?
+                .into_iter()
+                .collect_tuple()
+                .unwrap();
+            for (stack_index, name) in [(from, "from"), (to, "to")] {
+                if !(1..=amount_of_stacks).contains(&stack_index) {
+                    let cap = captures.name(name).unwrap().as_str();
+                    return Err(InputError::parse(
+                        format!("stack must be within 1..={amount_of_stacks}"),
+                        cap,
+                    )
+                    .located_in(input, cap));
+                }
+            }
+            Ok((amount, from, to))
+        })
+        .collect()
+}
+
+fn parse_filled_stacks(first_part: &str) -> Vec<VecDeque<char>> {
+    let first_part = first_part.lines().collect_vec();
+    let (first_part, middle_part) = first_part.split_at(first_part.len() - 1);
+    let amount_of_stacks = (middle_part[0].len() + 1) / 4;
+    let mut stack: Vec<VecDeque<char>> = (0..amount_of_stacks)
+        .map(|_| VecDeque::with_capacity(first_part.len()))
+        .collect_vec();
+
+    for line in first_part {
+        for (stack_index, curr_crate) in line.chars().skip(1).step_by(4).enumerate() {
+            if curr_crate != ' ' {
+                stack[stack_index].push_back(curr_crate);
+            }
+        }
+    }
+
+    stack
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve_part1("test.txt").unwrap(), "CMZ");
+    }
+
+    #[test]
+    fn verify1() {
+        assert_eq!(solve_part1("input.txt").unwrap(), "QMBMJDFTD");
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve_part2("test.txt").unwrap(), "MCD");
+    }
+
+    #[test]
+    fn invalid_moves() {
+        let input = "    [D]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 1\nmove x from 1 to 2\n";
+        let moves_part = input.split_once("\n\n").unwrap().1;
+        assert_eq!(
+            parse_moves(input, moves_part, 2).unwrap_err().to_string(),
+            "6:1: expected \"move <amount> from <from> to <to>\": \"move x from 1 to 2\""
+        );
+        let input = "move 1 from 2 to 1\nmove 1 from 3 to 2";
+        assert_eq!(
+            parse_moves(input, input, 2).unwrap_err().to_string(),
+            "2:13: stack must be within 1..=2: \"3\""
+        );
+    }
+
+    #[test]
+    fn verify2() {
+        assert_eq!(solve_part2("input.txt").unwrap(), "NBTVTJNFJ");
+    }
+}
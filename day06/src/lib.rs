@@ -0,0 +1,83 @@
+//#![allow(unused_imports)]
+//#![allow(dead_code)]
+//#![allow(unused_must_use)]
+#![deny(clippy::all, clippy::pedantic)]
+#![allow(
+    clippy::enum_glob_use,
+    clippy::many_single_char_names,
+    clippy::must_use_candidate
+)]
+#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::doc_markdown)]
+
+use itertools::Itertools;
+
+use utils::{Answer, InputResult, Solution};
+
+////////////////////////////////////////////////////////////////////////////////////
+
+pub fn solve_part1(file_name: &str) -> usize {
+    solve(file_name, 4)
+}
+
+pub fn solve_part2(file_name: &str) -> usize {
+    solve(file_name, 14)
+}
+
+/// Day 6 in the common [`Solution`] format
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Params = ();
+
+    fn part1(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part1(file_name).into())
+    }
+
+    fn part2(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part2(file_name).into())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+fn solve(file_name: &str, length_marker: usize) -> usize {
+    utils::bench::parse_phase(|| {
+        utils::file_to_string(file_name)
+            .replace("\r\n", "\n")
+            .chars()
+            .collect_vec()
+    })
+    .windows(length_marker)
+    .enumerate()
+    .find(|(_, window)| window.iter().all_unique())
+    .unwrap()
+    .0 + length_marker
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve_part1("test.txt"), 7);
+    }
+
+    #[test]
+    fn verify1() {
+        assert_eq!(solve_part1("input.txt"), 1702);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve_part2("test.txt"), 19);
+    }
+
+    #[test]
+    fn verify2() {
+        assert_eq!(solve_part2("input.txt"), 3559);
+    }
+}
@@ -0,0 +1,133 @@
+//#![allow(unused_imports)]
+//#![allow(dead_code)]
+//#![allow(unused_must_use)]
+#![deny(clippy::all, clippy::pedantic)]
+#![allow(
+    clippy::enum_glob_use,
+    clippy::many_single_char_names,
+    clippy::must_use_candidate
+)]
+#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::doc_markdown)]
+#![allow(clippy::unreadable_literal)]
+
+use grid::{
+    grid_array::GridArray,
+    grid_types::{Coor2D, Direction},
+};
+
+use utils::{Answer, InputResult, Solution};
+
+////////////////////////////////////////////////////////////////////////////////////
+
+pub fn solve_part1(file_name: &str) -> usize {
+    get_visible_trees(&utils::bench::parse_phase(|| create_forest_grid(file_name))).count()
+}
+
+pub fn solve_part2(file_name: &str) -> usize {
+    calc_scenic_scores(&utils::bench::parse_phase(|| create_forest_grid(file_name)))
+        .map(|(.., score)| score)
+        .max()
+        .unwrap()
+}
+
+/// Day 8 in the common [`Solution`] format
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Params = ();
+
+    fn part1(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part1(file_name).into())
+    }
+
+    fn part2(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part2(file_name).into())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+type MyGridArrayItemType = u8;
+type MyGridArray = GridArray<MyGridArrayItemType>;
+
+fn create_forest_grid(file_name: &str) -> MyGridArray {
+    utils::file_to_string(file_name).parse().unwrap()
+}
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::West,
+    Direction::East,
+    Direction::North,
+    Direction::South,
+];
+
+////////////////////////////////////////////////////////////////////////////////////
+
+fn get_visible_trees(forest: &MyGridArray) -> impl Iterator<Item = Coor2D> + '_ {
+    forest
+        .all_cells()
+        .filter(move |(coor, tree_size)| {
+            forest.is_edge(coor.x, coor.y)
+                || DIRECTIONS.into_iter().any(|direction| {
+                    forest.is_visible_from_edge(coor, direction, |(_, tree_size2)| {
+                        tree_size2 >= *tree_size
+                    })
+                })
+        })
+        .map(|(coor, _)| coor)
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+fn calc_scenic_scores(forest: &MyGridArray) -> impl Iterator<Item = (Coor2D, usize)> + '_ {
+    forest.all_cells().map(move |(coor, tree_size)| {
+        (
+            coor.clone(),
+            calc_scenic_score_x_y(forest, &coor, *tree_size),
+        )
+    })
+}
+
+fn calc_scenic_score_x_y(
+    forest: &MyGridArray,
+    coor: &Coor2D,
+    tree_size: MyGridArrayItemType,
+) -> usize {
+    // amount of trees visible for the house in each direction
+    DIRECTIONS
+        .into_iter()
+        .map(|direction| {
+            forest
+                .visible_from(coor, direction, |(_, tree_size2)| *tree_size2 >= tree_size)
+                .count()
+        })
+        .product()
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve_part1("test.txt"), 21);
+    }
+
+    #[test]
+    fn verify1() {
+        assert_eq!(solve_part1("input.txt"), 1809);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve_part2("test.txt"), 8);
+    }
+
+    #[test]
+    fn verify2() {
+        assert_eq!(solve_part2("input.txt"), 479400);
+    }
+}
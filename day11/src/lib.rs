@@ -0,0 +1,293 @@
+//#![allow(unused_imports)]
+//#![allow(dead_code)]
+//#![allow(unused_must_use)]
+#![deny(clippy::all, clippy::pedantic)]
+#![allow(
+    clippy::enum_glob_use,
+    clippy::many_single_char_names,
+    clippy::must_use_candidate
+)]
+#![allow(clippy::missing_panics_doc, clippy::missing_errors_doc)]
+#![allow(clippy::doc_markdown)]
+
+use std::{collections::VecDeque, str::FromStr};
+
+use itertools::Itertools;
+
+const DEBUG_PRINT: bool = false;
+
+use utils::{Answer, InputError, InputResult, Solution};
+
+////////////////////////////////////////////////////////////////////////////////////
+
+pub fn solve_part1(file_name: &str) -> InputResult<usize> {
+    Ok(utils::bench::parse_phase(|| MonkeyGame::new(file_name, 20, true))?.solve())
+}
+
+pub fn solve_part2(file_name: &str) -> InputResult<usize> {
+    Ok(utils::bench::parse_phase(|| MonkeyGame::new(file_name, 10000, false))?.solve())
+}
+
+/// Day 11 in the common [`Solution`] format
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Params = ();
+
+    fn part1(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        solve_part1(file_name).map(Into::into)
+    }
+
+    fn part2(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        solve_part2(file_name).map(Into::into)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+struct MonkeyGame {
+    monkey_vec: Vec<Monkey>,
+    rounds: usize,
+    product_of_divisible: usize,
+    divide_by_3: bool,
+}
+
+impl MonkeyGame {
+    fn new(file_name: &str, rounds: usize, divide_by_3: bool) -> InputResult<MonkeyGame> {
+        let lines = utils::try_file_to_lines(file_name)?.collect::<InputResult<Vec<_>>>()?;
+        let monkey_vec = lines
+            .chunks(7)
+            .enumerate()
+            .map(|(index, monkey)| Monkey::new(monkey, index * 7 + 1))
+            .collect::<InputResult<Vec<_>>>()
+            .map_err(|error| error.in_file(file_name))?;
+        if let Some(monkey_index) = monkey_vec
+            .iter()
+            .flat_map(|m| [m.monkey_index_true, m.monkey_index_false])
+            .find(|monkey_index| *monkey_index >= monkey_vec.len())
+        {
+            return Err(InputError::parse(
+                format!("there are only {} monkeys", monkey_vec.len()),
+                monkey_index.to_string(),
+            )
+            .in_file(file_name));
+        }
+        let product_of_divisible = monkey_vec.iter().map(|m| m.divisible_by).product();
+        Ok(MonkeyGame {
+            monkey_vec,
+            rounds,
+            product_of_divisible,
+            divide_by_3,
+        })
+    }
+
+    fn solve(&mut self) -> usize {
+        for round in 1..=self.rounds {
+            (0..self.monkey_vec.len()).for_each(|i| self.do_round_for_monkey(i));
+
+            if DEBUG_PRINT {
+                println!(
+                    "After round {round}, the monkeys are holding items with these worry levels:"
+                );
+                for (monkey_index, monkey) in self.monkey_vec.iter().enumerate() {
+                    println!(
+                        "Monkey {}: {:?}",
+                        monkey_index,
+                        monkey.items.iter().join(", ")
+                    );
+                }
+            }
+        }
+        self.calc_solution()
+    }
+
+    fn calc_solution(&mut self) -> usize {
+        self.monkey_vec
+            .iter()
+            .map(|m| m.item_inspection_count)
+            .sorted()
+            .rev()
+            .take(2)
+            .product()
+    }
+
+    fn do_round_for_monkey(&mut self, monkey_index: usize) {
+        while let Some(item) = self.monkey_vec[monkey_index].items.pop_front() {
+            let (new_item, new_monkey_index) = self.inspect_and_get_new_monkey(monkey_index, item);
+            self.monkey_vec[new_monkey_index].items.push_back(new_item);
+        }
+    }
+
+    fn inspect_and_get_new_monkey(&mut self, monkey_index: usize, item: usize) -> (usize, usize) {
+        let monkey = &mut self.monkey_vec[monkey_index];
+        let new_item = monkey.inspect_item(item, self.product_of_divisible, self.divide_by_3);
+        let new_monkey_index = monkey.get_next_monkey_index(new_item);
+        (new_item, new_monkey_index)
+    }
+}
+
+#[derive(Debug, Clone)]
+#[allow(clippy::struct_field_names)]
+struct Monkey {
+    items: VecDeque<usize>,
+    op: Operation,
+    divisible_by: usize,
+    monkey_index_true: usize,
+    monkey_index_false: usize,
+    item_inspection_count: usize,
+}
+
+impl Monkey {
+    fn get_next_monkey_index(&self, item: usize) -> usize {
+        if item.is_multiple_of(self.divisible_by) {
+            self.monkey_index_true
+        } else {
+            self.monkey_index_false
+        }
+    }
+
+    fn inspect_item(
+        &mut self,
+        item: usize,
+        product_of_divisible: usize,
+        divide_by_3: bool,
+    ) -> usize {
+        self.item_inspection_count += 1;
+        let mut result = self.op.apply(item);
+        if divide_by_3 {
+            result /= 3;
+        }
+        result % product_of_divisible
+    }
+
+    /// Parses the description of one monkey, `first_line` is the line number of "Monkey <n>:"
+    fn new(monkey: &[String], first_line: usize) -> InputResult<Monkey> {
+        // returns the text after the expected prefix in the given line of the description
+        let field = |index: usize, prefix: &str| {
+            let line_number = first_line + index;
+            let line = monkey.get(index).ok_or_else(|| {
+                InputError::parse(format!("expected \"{prefix}\""), "").at_line(line_number)
+            })?;
+            line.strip_prefix(prefix)
+                .map(|value| (line.as_str(), value, line_number))
+                .ok_or_else(|| {
+                    InputError::parse(format!("expected \"{prefix}\""), line.as_str())
+                        .at_line(line_number)
+                })
+        };
+        let number = |(line, value, line_number): (&str, &str, usize)| {
+            utils::try_str_to::<usize>(value)
+                .map_err(|error| error.in_line(line, value).at_line(line_number))
+        };
+
+        let (line, items, line_number) = field(1, "  Starting items: ")?;
+        let starting_items = items
+            .split(", ")
+            .map(|item| number((line, item, line_number)))
+            .collect::<InputResult<_>>()?;
+        let (line, op_str, line_number) = field(2, "  Operation: new = old ")?;
+        let op: Operation = Operation::from_str(op_str)
+            .map_err(|error| error.in_line(line, op_str).at_line(line_number))?;
+        let divisible_by = number(field(3, "  Test: divisible by ")?)?;
+        let monkey_true = number(field(4, "    If true: throw to monkey ")?)?;
+        let monkey_false = number(field(5, "    If false: throw to monkey ")?)?;
+        Ok(Monkey {
+            items: starting_items,
+            op,
+            divisible_by,
+            monkey_index_true: monkey_true,
+            monkey_index_false: monkey_false,
+            item_inspection_count: 0,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Add(usize),
+    Mul(usize),
+    Square,
+}
+impl Operation {
+    fn apply(&self, item: usize) -> usize {
+        match self {
+            Operation::Add(x) => item + x,
+            Operation::Mul(x) => item * x,
+            Operation::Square => item * item,
+        }
+    }
+}
+
+impl FromStr for Operation {
+    type Err = InputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (op, operand) = s
+            .split_whitespace()
+            .collect_tuple()
+            .ok_or_else(|| InputError::parse("expected \"<op> <operand>\"", s))?;
+        let operand_value =
+            || utils::try_str_to(operand).map_err(|error| error.in_line(s, operand));
+        match op {
+            "+" => Ok(Operation::Add(operand_value()?)),
+            "*" => {
+                if operand == "old" {
+                    Ok(Operation::Square)
+                } else {
+                    Ok(Operation::Mul(operand_value()?))
+                }
+            }
+            _ => Err(InputError::parse("expected \"+\" or \"*\"", op).in_line(s, op)),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve_part1("test.txt").unwrap(), 10605);
+    }
+
+    #[test]
+    fn verify1() {
+        assert_eq!(solve_part1("input.txt").unwrap(), 62491);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve_part2("test.txt").unwrap(), 2_713_310_158);
+    }
+
+    #[test]
+    fn invalid_monkey() {
+        let monkey = [
+            "Monkey 1:",
+            "  Starting items: 54, 6x5",
+            "  Operation: new = old * 19",
+            "  Test: divisible by 23",
+            "    If true: throw to monkey 2",
+            "    If false: throw to monkey 3",
+        ]
+        .map(String::from);
+        assert_eq!(
+            Monkey::new(&monkey, 8).unwrap_err().to_string(),
+            "9:23: invalid digit found in string: \"6x5\""
+        );
+        let mut monkey = monkey;
+        monkey[1] = "  Starting items: 54, 65".into();
+        assert_eq!(
+            Monkey::new(&monkey[..4], 8).unwrap_err().to_string(),
+            "12: expected \"    If true: throw to monkey \""
+        );
+    }
+
+    #[test]
+    fn verify2() {
+        assert_eq!(solve_part2("input.txt").unwrap(), 17_408_399_184);
+    }
+}
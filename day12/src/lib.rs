@@ -0,0 +1,109 @@
+//#![allow(unused_imports)]
+//#![allow(dead_code)]
+//#![allow(unused_must_use)]
+//#![deny(clippy::all, clippy::pedantic)]
+#![allow(
+    clippy::enum_glob_use,
+    clippy::many_single_char_names,
+    clippy::must_use_candidate
+)]
+#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::doc_markdown)]
+#![allow(clippy::unreadable_literal)]
+
+use grid::grid_array::GridArray;
+use grid::grid_trait::Grid;
+use grid::grid_types::{Coor2D, Neighborhood, Topology};
+
+use utils::{Answer, InputResult, Solution};
+
+////////////////////////////////////////////////////////////////////////////////////
+
+pub fn solve_part1(file_name: &str) -> usize {
+    let grid = utils::bench::parse_phase(|| parse_grid(file_name));
+
+    let start_pos = &find_first_pos(&grid, 'S');
+    let result = grid.bfs(start_pos, |(_, c)| *c == 'E', can_climb);
+    //println!("{:?}", result);
+    result.map(|result| result.1).unwrap()
+}
+
+pub fn solve_part2(file_name: &str) -> usize {
+    let grid = utils::bench::parse_phase(|| parse_grid(file_name));
+
+    // search from goal to any start pos
+    let goal_pos = &find_first_pos(&grid, 'E');
+
+    let result = grid.bfs(
+        goal_pos,
+        |(_, c)| *c == 'S' || *c == 'a',
+        |curr_cell, neighbor_cell| can_climb(neighbor_cell, curr_cell),
+    );
+    //println!("{:?}", result);
+    result.map(|result| result.1).unwrap()
+}
+
+/// Day 12 in the common [`Solution`] format
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Params = ();
+
+    fn part1(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part1(file_name).into())
+    }
+
+    fn part2(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part2(file_name).into())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+fn can_climb(curr_cell: (&Coor2D, &char), neighbor_cell: (&Coor2D, &char)) -> bool {
+    get_value(*neighbor_cell.1) <= get_value(*curr_cell.1) + 1
+}
+
+fn get_value(cell: char) -> u32 {
+    match cell {
+        'S' => 'a' as u32,
+        'E' => 'z' as u32,
+        _ => cell as u32,
+    }
+}
+
+fn find_first_pos(grid: &GridArray<char>, find_char: char) -> Coor2D {
+    grid.all_cells().find(|(_, c)| **c == find_char).unwrap().0
+}
+
+fn parse_grid(file_name: &str) -> GridArray<char> {
+    let input = utils::file_to_string(file_name);
+    GridArray::from_newline_separated_string(Topology::Bounded, Neighborhood::Orthogonal, &input)
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve_part1("test.txt"), 31);
+    }
+
+    #[test]
+    fn verify1() {
+        assert_eq!(solve_part1("input.txt"), 352);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve_part2("test.txt"), 29);
+    }
+
+    #[test]
+    fn verify2() {
+        assert_eq!(solve_part2("input.txt"), 345);
+    }
+}
@@ -0,0 +1,349 @@
+#![allow(unused_imports)]
+//#![allow(dead_code)]
+//#![allow(unused_must_use)]
+//#![deny(clippy::all, clippy::pedantic)]
+#![allow(
+    clippy::enum_glob_use,
+    clippy::many_single_char_names,
+    clippy::must_use_candidate
+)]
+#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::doc_markdown)]
+#![allow(clippy::unreadable_literal)]
+
+use std::{collections::HashSet, path::PathBuf};
+
+#[cfg(feature = "animation")]
+use grid::render::{GifRecorder, Rgb};
+use grid::{
+    draw,
+    grid_array::{GridArray, GridArrayBuilder},
+    grid_types::{Coor2D, Coor2DMut, Neighborhood, Topology},
+};
+use itertools::Itertools;
+
+use utils::{Answer, InputResult, Solution};
+
+////////////////////////////////////////////////////////////////////////////////////
+
+pub fn solve_part1(file_name: &str) -> usize {
+    let sand_entry = Coor2D::new(500, 0);
+    let (mut grid, rocks, max_rock_y) =
+        utils::bench::parse_phase(|| parse(file_name, &sand_entry, None));
+    //print_grid(&grid);
+
+    let file_name = create_image_filename(file_name, &sand_entry, 1);
+    let sand_count = simulate_sands(
+        &mut grid,
+        rocks,
+        &sand_entry,
+        Some(max_rock_y),
+        None,
+        file_name,
+    );
+    //print_grid(&grid);
+
+    sand_count
+}
+
+pub fn solve_part2(file_name: &str) -> usize {
+    let floor_y_diff = 2;
+    let sand_entry = Coor2D::new(500, 0);
+    let (mut grid, rocks, max_rock_y) =
+        utils::bench::parse_phase(|| parse(file_name, &sand_entry, Some(floor_y_diff)));
+    //print_grid(&grid);
+
+    let file_name = create_image_filename(file_name, &sand_entry, 2);
+    let sand_count = simulate_sands(
+        &mut grid,
+        rocks,
+        &sand_entry,
+        None,
+        Some(floor_y_diff + max_rock_y),
+        file_name,
+    );
+    //print_grid(&grid);
+
+    sand_count
+}
+
+/// Day 14 in the common [`Solution`] format
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Params = ();
+
+    fn part1(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part1(file_name).into())
+    }
+
+    fn part2(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part2(file_name).into())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+fn simulate_sands(
+    grid: &mut GridArray<char>,
+    mut solid_coors_set: HashSet<Coor2D>,
+    sand_entry: &Coor2D,
+    max_rock_y: Option<usize>,
+    floor_y: Option<usize>,
+    file_path: PathBuf,
+) -> usize {
+    let mut recorder = new_recorder(file_path);
+
+    let mut grid_vec = Vec::new();
+    save_grid(&mut recorder, grid, &mut grid_vec);
+    let mut sand_count = 0;
+    loop {
+        let sand_pos = let_sand_fall(
+            grid,
+            sand_entry,
+            &solid_coors_set,
+            max_rock_y,
+            floor_y,
+            &mut grid_vec,
+            &mut recorder,
+        );
+        match sand_pos {
+            None => break,
+            Some(sand_pos) if sand_pos == *sand_entry => {
+                sand_count += 1;
+                break;
+            }
+            Some(sand_pos) => {
+                grid.set(sand_pos.x, sand_pos.y, 'o');
+                solid_coors_set.insert(sand_pos);
+                sand_count += 1;
+            }
+        }
+        save_grid(&mut recorder, grid, &mut grid_vec);
+    }
+    save_animation(recorder);
+
+    sand_count
+}
+
+fn let_sand_fall(
+    grid: &mut GridArray<char>,
+    start_coor: &Coor2D,
+    solid_coors_set: &HashSet<Coor2D>,
+    max_rock_y: Option<usize>,
+    floor_y: Option<usize>,
+    grid_vec: &mut [GridArray<char>],
+    recorder: &mut Option<Recorder>,
+) -> Option<Coor2D> {
+    let no_solid = |coor: Coor2D| {
+        if solid_coors_set.contains(&coor) || (floor_y.is_some() && coor.y >= floor_y.unwrap()) {
+            None
+        } else {
+            Some(coor)
+        }
+    };
+
+    let mut add_frame = |curr_coor: &Coor2D, next_coor: &Coor2D| {
+        if recorder.is_some() {
+            grid.set(curr_coor.x, curr_coor.y, '\0');
+            grid.set(next_coor.x, next_coor.y, '+');
+            grid.set(start_coor.x, start_coor.y, '+');
+            save_grid(recorder, grid, grid_vec);
+        }
+    };
+
+    let mut curr_coor = start_coor.clone();
+    loop {
+        let next_coor = curr_coor.clone() + Coor2D::new(0, 1);
+        if max_rock_y.is_some() && next_coor.y > max_rock_y.unwrap() {
+            return None;
+        }
+        if let Some(next_coor) = no_solid(next_coor.clone()) {
+            add_frame(&curr_coor, &next_coor);
+
+            curr_coor = next_coor;
+            continue;
+        }
+        if let Some(next_coor) = no_solid(next_coor.clone() - Coor2D::new(1, 0)) {
+            add_frame(&curr_coor, &next_coor);
+
+            curr_coor = next_coor;
+            continue;
+        }
+        if let Some(next_coor) = no_solid(next_coor.clone() + Coor2D::new(1, 0)) {
+            add_frame(&curr_coor, &next_coor);
+
+            curr_coor = next_coor;
+            continue;
+        }
+
+        // all blocked
+        return Some(curr_coor);
+    }
+}
+
+fn get_minmax_nonempty(grid: &GridArray<char>) -> (Coor2D, Coor2D) {
+    grid.all_cells().filter(|(_, ch)| ch != &&'\0').fold(
+        (
+            Coor2D::new(usize::MAX, usize::MAX),
+            Coor2D::new(usize::MIN, usize::MIN),
+        ),
+        |(coor_min, coor_max), (coor, _)| (coor_min.min(&coor), coor_max.max(&coor)),
+    )
+}
+
+///////////////////////////////////////////////////////////////////////////////////////
+
+fn create_image_filename(file_name: &str, sand_entry: &Coor2D, part_number: usize) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "{}_{}x{}_part{}.gif",
+        file_name.to_string().replace('/', "_"),
+        sand_entry.x,
+        sand_entry.y,
+        part_number
+    ))
+}
+
+fn parse(
+    file_name: &str,
+    sand_entry: &Coor2D,
+    floor_y_diff: Option<usize>,
+) -> (GridArray<char>, HashSet<Coor2D>, usize) {
+    let rocks = parse_rock_data(file_name);
+    let max_coor: Coor2D = rocks
+        .iter()
+        .fold(
+            Coor2D::new(usize::MIN, usize::MIN),
+            |acc: Coor2D, e: &Coor2D| acc.max(e),
+        )
+        .max(sand_entry);
+    let mut grid: GridArray<char> = GridArrayBuilder::default()
+        .topology(Topology::Bounded)
+        .neighborhood(Neighborhood::Square)
+        .width(max_coor.x + max_coor.y + 1)
+        .height(max_coor.y + floor_y_diff.unwrap_or(0) + 1)
+        .build()
+        .unwrap();
+    for coor in &rocks {
+        grid.set(coor.x, coor.y, '#');
+    }
+    grid.set(sand_entry.x, sand_entry.y, '+');
+    (grid, rocks, max_coor.y)
+}
+
+fn parse_rock_data(file_name: &str) -> HashSet<Coor2D> {
+    utils::file_to_lines(file_name)
+        .flat_map(|line| {
+            draw::polyline(line.split(" -> ").map(|t| {
+                Coor2DMut::from_tuple(t.split(',').map(utils::str_to).collect_tuple().unwrap())
+            }))
+            .collect_vec()
+        })
+        .map(|coor| Coor2D::new(coor.x.try_into().unwrap(), coor.y.try_into().unwrap()))
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+#[allow(dead_code)]
+fn print_grid(grid: &GridArray<char>) {
+    if cfg!(test) {
+        return;
+    }
+    let (min_coor, max_coor) = get_minmax_nonempty(grid);
+    for y in min_coor.y..=max_coor.y {
+        for x in min_coor.x..=max_coor.x {
+            let ch = grid.get_unchecked(x, y);
+            if ch == &'\0' {
+                print!(".");
+            } else {
+                print!("{ch}");
+            }
+        }
+        println!();
+    }
+}
+
+/// Records the frames of the animation, without the feature `animation` there is never a recorder
+#[cfg(feature = "animation")]
+type Recorder = GifRecorder;
+#[cfg(not(feature = "animation"))]
+type Recorder = std::convert::Infallible;
+
+/// The animation is only rendered with `--features animation` and not in tests (slow, writes a
+/// gif file)
+#[cfg(all(feature = "animation", not(test)))]
+fn new_recorder(file_path: PathBuf) -> Option<Recorder> {
+    Some(GifRecorder::new(file_path))
+}
+
+#[cfg(not(all(feature = "animation", not(test))))]
+fn new_recorder(_file_path: PathBuf) -> Option<Recorder> {
+    None
+}
+
+#[cfg(feature = "animation")]
+fn sand_palette(ch: &char) -> Rgb {
+    match ch {
+        '#' => [160, 160, 160],
+        'o' => [255, 217, 50],
+        '+' => [255 / 2, 217 / 2, 50 / 2],
+        _ => [0, 0, 0],
+    }
+}
+
+#[cfg(feature = "animation")]
+fn save_grid(
+    recorder: &mut Option<Recorder>,
+    grid: &GridArray<char>,
+    _grid_vec: &mut [GridArray<char>],
+) {
+    if let Some(recorder) = recorder {
+        recorder.record_grid_array(grid, sand_palette).unwrap();
+    }
+}
+
+#[cfg(not(feature = "animation"))]
+fn save_grid(
+    _recorder: &mut Option<Recorder>,
+    _grid: &GridArray<char>,
+    _grid_vec: &mut [GridArray<char>],
+) {
+}
+
+#[cfg(feature = "animation")]
+fn save_animation(recorder: Option<Recorder>) {
+    if let Some(mut recorder) = recorder {
+        recorder.save().unwrap();
+    }
+}
+
+#[cfg(not(feature = "animation"))]
+fn save_animation(_recorder: Option<Recorder>) {}
+
+////////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve_part1("test.txt"), 24);
+    }
+
+    #[test]
+    fn verify1() {
+        assert_eq!(solve_part1("input.txt"), 885);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve_part2("test.txt"), 93);
+    }
+
+    #[test]
+    fn verify2() {
+        assert_eq!(solve_part2("input.txt"), 28691);
+    }
+}
@@ -0,0 +1,231 @@
+//#![allow(unused_imports)]
+//#![allow(dead_code)]
+//#![allow(unused_must_use)]
+#![deny(clippy::all, clippy::pedantic)]
+#![allow(
+    clippy::enum_glob_use,
+    clippy::many_single_char_names,
+    clippy::must_use_candidate
+)]
+#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::doc_markdown)]
+#![allow(clippy::unreadable_literal)]
+
+use std::{collections::HashSet, fmt::Display};
+
+use grid::{
+    grid_hashmap::{GridHashMap, GridHashMapBuilder},
+    grid_types::{Coor2DMut, Neighborhood},
+};
+use itertools::Itertools;
+
+use gcollections::ops::{Bounded, Contains, Empty, Intersection, Union};
+use interval::interval_set::{IntervalSet, ToIntervalSet};
+
+use utils::{Answer, InputKind, InputResult, Solution};
+
+////////////////////////////////////////////////////////////////////////////////////
+
+pub fn solve_part1(file_name: &str, row: isize) -> usize {
+    let input = utils::bench::parse_phase(|| parse_sensor_beacon_list(file_name));
+    let mut grid = create_grid(&input);
+
+    //grid.print('.');
+    //println!("");
+
+    for (sensor, beacon) in &input {
+        let max_manhattan_distance = sensor.manhattan_distance(beacon);
+
+        // can this sensor / beacon combination influence the row?
+        // row must be in reach of sensor + (distance between sensor and beacon)
+        let sensor_row_distance = sensor.y - row;
+        if sensor_row_distance.abs() > max_manhattan_distance.try_into().unwrap() {
+            continue;
+        }
+
+        // not all neighbors are relevant, only those that match row
+        for neighboor in
+            get_all_neighbors_within_for_row(&grid, sensor, max_manhattan_distance, row)
+        {
+            if grid.get(&neighboor).is_none() || grid.get(&neighboor) == Some(&'.') {
+                grid.set(neighboor, '#');
+            }
+        }
+    }
+    //grid.print('.');
+    //println!("");
+
+    grid.all_cells()
+        .filter(|(coor, _)| coor.y == row)
+        .filter(|(_, ch)| ch == &Some(&'#'))
+        .count()
+}
+
+pub fn solve_part2(file_name: &str, max_x: isize) -> isize {
+    let input = utils::bench::parse_phase(|| parse_sensor_beacon_list(file_name));
+    let full_interval = vec![(0, max_x)].to_interval_set();
+
+    for row in (0..=max_x).rev() {
+        let mut interval = Vec::new().to_interval_set();
+        for (sensor, beacon) in &input {
+            interval = interval.union(&sensor_beacon_row_interval(sensor, beacon, row));
+        }
+
+        let intersect = interval.intersection(&full_interval);
+        if intersect != full_interval {
+            let first_interval = intersect.iter().next().unwrap();
+            let x = first_interval.upper() + 1;
+            assert!(!intersect.contains(&x));
+            return x * 4_000_000 + row;
+        }
+    }
+
+    unreachable!()
+}
+
+/// Rows to inspect, they differ between example and puzzle input
+pub struct Params {
+    /// row checked in part 1
+    pub row: isize,
+    /// maximal x and y coordinate searched in part 2
+    pub max_x: isize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            row: 2_000_000,
+            max_x: 4_000_000,
+        }
+    }
+}
+
+/// Day 15 in the common [`Solution`] format
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Params = Params;
+
+    fn params(kind: InputKind) -> Params {
+        match kind {
+            InputKind::Example => Params { row: 10, max_x: 20 },
+            InputKind::Puzzle => Params::default(),
+        }
+    }
+
+    fn part1(file_name: &str, params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part1(file_name, params.row).into())
+    }
+
+    fn part2(file_name: &str, params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part2(file_name, params.max_x).into())
+    }
+}
+
+fn sensor_beacon_row_interval(
+    sensor: &Coor2DMut<isize>,
+    beacon: &Coor2DMut<isize>,
+    row: isize,
+) -> IntervalSet<isize> {
+    let radius: isize = sensor.manhattan_distance(beacon).try_into().unwrap();
+    let offset = radius - (sensor.y - row).abs();
+    if offset < 0 {
+        IntervalSet::empty()
+    } else {
+        vec![(sensor.x - offset, sensor.x + offset)].to_interval_set()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+fn create_grid(input: &[(Coor2DMut<isize>, Coor2DMut<isize>)]) -> GridHashMap<char> {
+    let mut grid: GridHashMap<char> = GridHashMapBuilder::default()
+        .neighborhood(Neighborhood::Orthogonal)
+        .build()
+        .unwrap();
+    for (sensor, beacon) in input {
+        grid.set(sensor.clone(), 'S');
+        grid.set(beacon.clone(), 'B');
+    }
+    grid
+}
+
+fn parse_sensor_beacon_list<T>(file_name: &str) -> Vec<(Coor2DMut<T>, Coor2DMut<T>)>
+where
+    T: Clone + Ord + Eq + Display + std::str::FromStr,
+    <T>::Err: std::fmt::Debug,
+{
+    utils::file_to_lines(file_name)
+        .map(|line| {
+            let line = line
+                .replace("Sensor at x=", "")
+                .replace(": closest beacon is at x=", ",")
+                .replace(", y=", ",");
+            let (sensor_x, sensor_y, beacon_x, beacon_y) = line
+                .split(',')
+                .map(utils::str_to::<T>)
+                .collect_tuple()
+                .unwrap();
+            let sensor = Coor2DMut::new(sensor_x, sensor_y);
+            let beacon = Coor2DMut::new(beacon_x, beacon_y);
+            (sensor, beacon)
+        })
+        .collect_vec()
+}
+
+fn get_all_neighbors_within_for_row(
+    _grid: &GridHashMap<char>,
+    sensor_coor: &Coor2DMut<isize>,
+    max_manhattan_distance: usize,
+    row: isize,
+) -> HashSet<Coor2DMut<isize>> {
+    let mut neighbors_within = HashSet::new();
+    let mut distance_to_sensor_x = 0;
+
+    loop {
+        if sensor_coor
+            .manhattan_distance(&Coor2DMut::new(sensor_coor.x + distance_to_sensor_x, row))
+            > max_manhattan_distance
+        {
+            break;
+        }
+        neighbors_within.insert(Coor2DMut::new(sensor_coor.x + distance_to_sensor_x, row));
+        neighbors_within.insert(Coor2DMut::new(sensor_coor.x - distance_to_sensor_x, row));
+        distance_to_sensor_x += 1;
+    }
+
+    neighbors_within
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve_part1("test.txt", 10), 26);
+    }
+
+    #[test]
+    fn test1_solution() {
+        let params = Day15::params(InputKind::Example);
+        assert_eq!(Day15::part1("test.txt", &params).unwrap(), Answer::Int(26));
+    }
+
+    #[test]
+    fn verify1() {
+        assert_eq!(solve_part1("input.txt", 2000000), 5299855);
+    }
+
+    #[test]
+    fn test2() {
+        //assert_eq!(solve_part2("test.txt", 20), 56000011);
+    }
+
+    #[test]
+    fn verify2() {
+        assert_eq!(solve_part2("input.txt", 4000000), 13615843289729);
+    }
+}
@@ -0,0 +1,341 @@
+//#![allow(unused_imports)]
+//#![allow(dead_code)]
+//#![allow(unused_must_use)]
+#![deny(clippy::all, clippy::pedantic)]
+#![allow(
+    clippy::enum_glob_use,
+    clippy::many_single_char_names,
+    clippy::must_use_candidate
+)]
+#![allow(clippy::missing_panics_doc, clippy::missing_errors_doc)]
+#![allow(clippy::doc_markdown)]
+
+use fxhash::FxHashMap;
+use rayon::prelude::*;
+
+use itertools::Itertools;
+
+use pathfinding::prelude::dijkstra;
+
+use utils::{Answer, InputError, InputResult, Solution};
+
+////////////////////////////////////////////////////////////////////////////////////
+
+pub fn solve_part1(file_name: &str) -> InputResult<usize> {
+    let tunnel_system = TunnelSystem::new(30, utils::bench::parse_phase(|| parse(file_name))?);
+    Ok(tunnel_system.get_max_pressure(tunnel_system.valves_with_flow.clone()))
+}
+
+pub fn solve_part2(file_name: &str) -> InputResult<usize> {
+    let tunnel_system = TunnelSystem::new(26, utils::bench::parse_phase(|| parse(file_name))?);
+
+    // you have two persons now that can work in parallel, call get_max_pressure twice with every possible split of the valves
+    Ok(tunnel_system
+        .create_splits()
+        .par_iter() // parallelize
+        .cloned()
+        .map(|(valve_set_1, valve_set_2)| {
+            tunnel_system.get_max_pressure(valve_set_1)
+                + tunnel_system.get_max_pressure(valve_set_2)
+        })
+        .max()
+        .unwrap())
+}
+
+/// Day 16 in the common [`Solution`] format
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Params = ();
+
+    fn part1(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        solve_part1(file_name).map(Into::into)
+    }
+
+    fn part2(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        solve_part2(file_name).map(Into::into)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+type ValveId = usize;
+type ValveIdMap = FxHashMap<ValveId, Valve>;
+type DistanceHashMap = FxHashMap<(ValveId, ValveId), usize>;
+type ValveIdVec = Vec<ValveId>;
+
+#[derive(Debug, Clone)]
+struct Valve {
+    id: ValveId,
+    flow_rate: usize,
+    tunnels: Vec<ValveId>,
+}
+
+impl Eq for Valve {}
+
+impl PartialEq for Valve {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl core::hash::Hash for Valve {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Valve {
+    fn valve_string_to_number(valve: &str) -> ValveId {
+        let mut result = 0;
+        for c in valve.chars() {
+            result = result * 26 + (c as usize - 'A' as usize + 1);
+        }
+        result as ValveId
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct TunnelSystem {
+    limit: usize,
+    valves: ValveIdMap,
+    valves_with_flow: ValveIdVec,
+    valve_to_valve_distances: DistanceHashMap,
+}
+
+impl TunnelSystem {
+    fn new(limit: usize, valves: ValveIdMap) -> Self {
+        let valves_with_flow = Self::_create_valves_with_flow(&valves);
+
+        let valve_to_valve_distances =
+            Self::_create_valve_to_valve_distances(&valves, &valves_with_flow);
+
+        TunnelSystem {
+            limit,
+            valves,
+            valves_with_flow,
+            valve_to_valve_distances,
+        }
+    }
+
+    fn _create_valves_with_flow(valves: &ValveIdMap) -> ValveIdVec {
+        valves
+            .iter()
+            .filter(|(_, v)| v.flow_rate > 0)
+            .map(|(k, _)| *k)
+            .collect_vec()
+    }
+
+    fn _create_valve_to_valve_distances(
+        valves: &ValveIdMap,
+        valves_with_flow: &ValveIdVec,
+    ) -> DistanceHashMap {
+        let start_node = Valve::valve_string_to_number("AA");
+        valves_with_flow
+            .iter()
+            .chain(vec![&start_node])
+            .cartesian_product(valves_with_flow.iter())
+            .map(|(start_valve, goal_valve)| {
+                let result = dijkstra(
+                    start_valve,
+                    |node: &ValveId| -> Vec<(ValveId, usize)> {
+                        valves[node].tunnels.iter().map(|t| (*t, 1)).collect_vec()
+                    },
+                    |node| node == goal_valve,
+                );
+                ((*start_valve, *goal_valve), result.unwrap().1)
+            })
+            .collect()
+    }
+
+    fn create_splits(&self) -> Vec<(Vec<ValveId>, Vec<ValveId>)> {
+        // only need to split until half since the other half is the same (mirrored)
+        (0..=self.valves_with_flow.len() / 2)
+            .flat_map(move |i| self.create_i_sized_splits(i))
+            .collect_vec()
+    }
+
+    fn create_i_sized_splits(&self, i: usize) -> Vec<(Vec<ValveId>, Vec<ValveId>)> {
+        self.valves_with_flow
+            .iter()
+            .copied()
+            .combinations(i)
+            .map(move |valve_set_1| {
+                let valve_set_2 = self
+                    .valves_with_flow
+                    .iter()
+                    .copied()
+                    .filter(|v| !valve_set_1.contains(v))
+                    .collect_vec();
+                (valve_set_1, valve_set_2)
+            })
+            .collect_vec()
+    }
+
+    fn get_max_pressure(&self, remaining: ValveIdVec) -> usize {
+        let start_node = Node {
+            tunnel: Valve::valve_string_to_number("AA"),
+            time: 0,
+            pressure: 0,
+            flow: 0,
+            remaining,
+        };
+        start_node.get_max_pressure_rec(&self.valve_to_valve_distances, &self.valves, self.limit)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    tunnel: ValveId,
+    time: usize,
+    pressure: usize,
+    flow: usize,
+    remaining: ValveIdVec,
+}
+
+impl Node {
+    fn get_max_pressure_rec(
+        &self,
+        valve_shorted_pathes: &DistanceHashMap,
+        valves: &ValveIdMap,
+        limit: usize,
+    ) -> usize {
+        let pressure_at_end = self.pressure + (limit - self.time) * self.flow;
+        let max_pressure_rec = self
+            .remaining
+            .iter()
+            .filter_map(|new_tunnel| {
+                self.get_max_pressure_rec_for_tunnel(
+                    *new_tunnel,
+                    valve_shorted_pathes,
+                    valves,
+                    limit,
+                )
+            })
+            .max();
+        pressure_at_end.max(max_pressure_rec.unwrap_or(0))
+    }
+
+    fn get_max_pressure_rec_for_tunnel(
+        &self,
+        new_tunnel: ValveId,
+        valve_shorted_pathes: &DistanceHashMap,
+        valves: &ValveIdMap,
+        limit: usize,
+    ) -> Option<usize> {
+        let needed_minutes = valve_shorted_pathes[&(self.tunnel, new_tunnel)] + 1;
+        // + distance and +1 for open
+        if self.time + needed_minutes > limit {
+            None
+        } else {
+            let new_remaining = self
+                .remaining
+                .iter()
+                .copied()
+                .filter(|v| *v != new_tunnel)
+                .collect_vec();
+            let new_node = Node {
+                tunnel: new_tunnel,
+                time: self.time + needed_minutes,
+                pressure: self.pressure + needed_minutes * self.flow,
+                flow: self.flow + valves[&new_tunnel].flow_rate,
+                remaining: new_remaining,
+            };
+            Some(new_node.get_max_pressure_rec(valve_shorted_pathes, valves, limit))
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+fn parse(file_name: &str) -> InputResult<ValveIdMap> {
+    let mut valves = ValveIdMap::default();
+    for (line_index, line) in utils::try_file_to_lines(file_name)?.enumerate() {
+        let valve = parse_valve(&line?)
+            .map_err(|error| error.at_line(line_index + 1).in_file(file_name))?;
+        valves.insert(valve.id, valve);
+    }
+    if !valves.contains_key(&Valve::valve_string_to_number("AA")) {
+        return Err(InputError::parse("missing start valve", "AA").in_file(file_name));
+    }
+    Ok(valves)
+}
+
+/// Parses "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB"
+fn parse_valve(line: &str) -> InputResult<Valve> {
+    let expected = |message: &str, part: &str| InputError::parse(message, part).in_line(line, part);
+    let valve_name = |name: &str| {
+        if name.len() == 2 && name.chars().all(|c| c.is_ascii_uppercase()) {
+            Ok(Valve::valve_string_to_number(name))
+        } else {
+            Err(expected(
+                "expected two uppercase letters as valve name",
+                name,
+            ))
+        }
+    };
+
+    let rest = line
+        .strip_prefix("Valve ")
+        .ok_or_else(|| expected("expected \"Valve \"", line))?;
+    let (node, rest) = rest
+        .split_once(" has flow rate=")
+        .ok_or_else(|| expected("expected \" has flow rate=\"", rest))?;
+    let (flow_rate, rest) = rest
+        .split_once("; ")
+        .ok_or_else(|| expected("expected \"; \"", rest))?;
+    let edges = rest
+        .strip_prefix("tunnels lead to valves ")
+        .or_else(|| rest.strip_prefix("tunnel leads to valve "))
+        .ok_or_else(|| expected("expected \"tunnel(s) lead(s) to valve(s) \"", rest))?;
+
+    Ok(Valve {
+        id: valve_name(node)?,
+        flow_rate: utils::try_str_to(flow_rate).map_err(|error| error.in_line(line, flow_rate))?,
+        tunnels: edges.split(", ").map(valve_name).try_collect()?,
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve_part1("test.txt").unwrap(), 1651);
+    }
+
+    #[test]
+    fn verify1() {
+        assert_eq!(solve_part1("input.txt").unwrap(), 1741);
+    }
+
+    #[test]
+    fn invalid_valve() {
+        assert_eq!(
+            parse_valve("Valve AA has flow rate=x; tunnels lead to valves DD, II")
+                .unwrap_err()
+                .to_string(),
+            "col 24: invalid digit found in string: \"x\""
+        );
+        assert_eq!(
+            parse_valve("Valve AA has flow rate=0; tunnel leads to valve d")
+                .unwrap_err()
+                .to_string(),
+            "col 49: expected two uppercase letters as valve name: \"d\""
+        );
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve_part2("test.txt").unwrap(), 1707);
+    }
+
+    #[test]
+    fn verify2() {
+        assert_eq!(solve_part2("input.txt").unwrap(), 2316);
+    }
+}
@@ -0,0 +1,85 @@
+//#![allow(unused_imports)]
+//#![allow(dead_code)]
+//#![allow(unused_must_use)]
+//#![deny(clippy::all, clippy::pedantic)]
+#![allow(
+    clippy::enum_glob_use,
+    clippy::many_single_char_names,
+    clippy::must_use_candidate
+)]
+#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::doc_markdown)]
+#![allow(clippy::unreadable_literal)]
+
+mod floor;
+mod rock;
+use floor::Floor;
+
+use utils::{Answer, InputResult, Solution};
+
+////////////////////////////////////////////////////////////////////////////////////
+
+// test: 1*41=41
+// input: 2*2*3*29*29 = 10092
+
+const WIDTH: usize = 7;
+const REMOVE_UNREACHABLE_LINES: bool = false;
+const ROCK_AMOUNT: usize = 5;
+const DRAW_FALLING_ROCKS: bool = false;
+const DRAW_FLOOR: bool = false;
+
+pub fn solve_part1(file_name: &str) -> usize {
+    Floor::new(utils::bench::parse_phase(|| {
+        utils::file_to_string(file_name)
+    }))
+    .solve(2022)
+}
+
+pub fn solve_part2(file_name: &str) -> usize {
+    Floor::new(utils::bench::parse_phase(|| {
+        utils::file_to_string(file_name)
+    }))
+    .solve(1_000_000_000_000)
+}
+
+/// Day 17 in the common [`Solution`] format
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Params = ();
+
+    fn part1(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part1(file_name).into())
+    }
+
+    fn part2(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part2(file_name).into())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve_part1("test.txt"), 3068);
+    }
+
+    #[test]
+    fn verify1() {
+        assert_eq!(solve_part1("input.txt"), 3114);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve_part2("test.txt"), 1514285714288);
+    }
+
+    #[test]
+    fn verify2() {
+        assert_eq!(solve_part2("input.txt"), 1540804597682);
+    }
+}
@@ -0,0 +1,118 @@
+//#![allow(unused_imports)]
+//#![allow(dead_code)]
+//#![allow(unused_must_use)]
+#![deny(clippy::all, clippy::pedantic)]
+#![allow(
+    clippy::enum_glob_use,
+    clippy::many_single_char_names,
+    clippy::must_use_candidate
+)]
+#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::doc_markdown)]
+#![allow(clippy::unreadable_literal)]
+
+use fxhash::FxHashSet;
+use grid::grid_hashmap_3d::GridHashMap3D;
+use grid::grid_types::{Coor3DMut, Neighborhood3D};
+use itertools::Itertools;
+
+use utils::{Answer, InputResult, Solution};
+
+////////////////////////////////////////////////////////////////////////////////////
+
+type Coor = Coor3DMut<isize>;
+
+pub fn solve_part1(file_name: &str) -> usize {
+    let cubes = utils::bench::parse_phase(|| parse(file_name));
+    count_faces(&cubes, |(_, cell)| cell.is_none())
+}
+
+pub fn solve_part2(file_name: &str) -> usize {
+    let cubes = utils::bench::parse_phase(|| parse(file_name));
+
+    // the bounding box grown by one is surrounded by air, everything reachable from its corner is outside
+    let space = GridHashMap3D::from_cells(
+        Neighborhood3D::Face,
+        cubes
+            .all_indexes_in_bounding_box(1)
+            .map(|coor| (coor.clone(), cubes.get(&coor).copied().unwrap_or('.'))),
+    );
+    let outside: FxHashSet<Coor> = space
+        .flood_fill(&space.get_min_max().0, |(_, cell)| *cell == '.')
+        .unwrap()
+        .cells
+        .into_iter()
+        .collect();
+
+    count_faces(&cubes, |(coor, _)| outside.contains(coor))
+}
+
+/// Day 18 in the common [`Solution`] format
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Params = ();
+
+    fn part1(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part1(file_name).into())
+    }
+
+    fn part2(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part2(file_name).into())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+fn parse(file_name: &str) -> GridHashMap3D<char> {
+    GridHashMap3D::from_cells(
+        Neighborhood3D::Face,
+        utils::file_to_lines(file_name).map(|line| {
+            let coor = line.split(',').map(utils::str_to).collect_tuple().unwrap();
+            (Coor::from_tuple(coor), '#')
+        }),
+    )
+}
+
+/// Number of faces of the cubes whose neighbor matches `is_open`
+fn count_faces(
+    cubes: &GridHashMap3D<char>,
+    mut is_open: impl FnMut((&Coor, Option<&char>)) -> bool,
+) -> usize {
+    cubes
+        .all_indexes()
+        .map(|cube| {
+            cubes
+                .neighborhood_cells(&cube)
+                .filter(|(neighbor, cell)| is_open((neighbor, *cell)))
+                .count()
+        })
+        .sum()
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve_part1("test.txt"), 64);
+    }
+
+    #[test]
+    fn verify1() {
+        assert_eq!(solve_part1("input.txt"), 3586);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve_part2("test.txt"), 58);
+    }
+
+    #[test]
+    fn verify2() {
+        assert_eq!(solve_part2("input.txt"), 2072);
+    }
+}
@@ -0,0 +1,106 @@
+//#![allow(unused_imports)]
+//#![allow(dead_code)]
+//#![allow(unused_must_use)]
+//#![deny(clippy::all, clippy::pedantic)]
+#![allow(
+    clippy::enum_glob_use,
+    clippy::many_single_char_names,
+    clippy::must_use_candidate
+)]
+#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::doc_markdown)]
+#![allow(clippy::unreadable_literal)]
+
+use utils::{Answer, InputResult, Solution};
+
+////////////////////////////////////////////////////////////////////////////////////
+
+pub fn solve_part1(file_name: &str) -> isize {
+    solve(utils::bench::parse_phase(|| parse(file_name, 1)), 1)
+}
+
+pub fn solve_part2(file_name: &str) -> isize {
+    const DECRYPTION_KEY: isize = 811589153;
+    solve(
+        utils::bench::parse_phase(|| parse(file_name, DECRYPTION_KEY)),
+        10,
+    )
+}
+
+/// Day 20 in the common [`Solution`] format
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Params = ();
+
+    fn part1(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part1(file_name).into())
+    }
+
+    fn part2(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part2(file_name).into())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+fn solve(original_input: Vec<(isize, usize)>, times: usize) -> isize {
+    let mut input = original_input.clone();
+    for _ in 0..times {
+        for value in &original_input {
+            let index = input.iter().position(|x| *x == *value).unwrap();
+            rotate(&mut input, index);
+        }
+    }
+    compute_result(input)
+}
+
+fn compute_result(input: Vec<(isize, usize)>) -> isize {
+    let zero_pos = input.iter().position(|(x, _counter)| *x == 0).unwrap();
+    [1000, 2000, 3000]
+        .into_iter()
+        .map(|pos| input[(zero_pos + pos) % input.len()].0)
+        .sum()
+}
+
+fn rotate(input: &mut Vec<(isize, usize)>, index: usize) {
+    let value = input.remove(index);
+    // rust modulo is not the same as python modulo (-1 % 5 = -1 in rust, -1 % 5 = 4 in python) => rem_euclid
+    let new_index = ((index as isize) + value.0).rem_euclid(input.len() as isize) as usize;
+    input.insert(new_index, value);
+}
+
+fn parse(file_name: &str, decryption_key: isize) -> Vec<(isize, usize)> {
+    utils::file_to_lines(file_name)
+        .map(move |line| utils::str_to::<isize>(line.as_str()) * decryption_key)
+        .enumerate()
+        .map(|(index, x)| (x, index))
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve_part1("test.txt"), 3);
+    }
+
+    #[test]
+    fn verify1() {
+        assert_eq!(solve_part1("input.txt"), 7004);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve_part2("test.txt"), 1623178306);
+    }
+
+    #[test]
+    fn verify2() {
+        assert_eq!(solve_part2("input.txt"), 17200008919529);
+    }
+}
@@ -0,0 +1,336 @@
+//#![allow(unused_imports)]
+//#![allow(dead_code)]
+//#![allow(unused_must_use)]
+#![deny(clippy::all, clippy::pedantic)]
+#![allow(
+    clippy::enum_glob_use,
+    clippy::many_single_char_names,
+    clippy::must_use_candidate
+)]
+#![allow(clippy::missing_panics_doc, clippy::missing_errors_doc)]
+#![allow(clippy::doc_markdown)]
+#![allow(clippy::unreadable_literal)]
+
+use std::str::FromStr;
+
+use derive_more::{IsVariant, Unwrap};
+use fxhash::FxHashMap;
+use itertools::Itertools;
+
+use utils::{Answer, InputError, InputResult, Solution};
+
+////////////////////////////////////////////////////////////////////////////////////
+
+type MonkeyIndex = String;
+const ROOT_MONKEY: &str = "root";
+const HUMAN_MONKEY: &str = "humn";
+
+pub fn solve_part1(file_name: &str) -> InputResult<isize> {
+    let monkey_map = utils::bench::parse_phase(|| parse(file_name))?;
+    Ok(monkey_map.get(ROOT_MONKEY).unwrap().eval(&monkey_map))
+}
+
+pub fn solve_part2(file_name: &str) -> InputResult<isize> {
+    let mut monkey_map = utils::bench::parse_phase(|| parse(file_name))?;
+    //println!("{:?}", monkey_map);
+
+    // Replace human entry
+    monkey_map.insert(
+        HUMAN_MONKEY.to_string(),
+        MonkeyRule::Human(HUMAN_MONKEY.to_string()),
+    );
+
+    let (monkey1, monkey2) = monkey_map
+        .get(ROOT_MONKEY)
+        .unwrap()
+        .get_used_monkeys_iter(&monkey_map)
+        .collect_tuple()
+        .unwrap();
+    // only rule1 depends on human in the data
+    assert_eq!(monkey1.count_human_usage(&monkey_map), 1);
+    assert_eq!(monkey2.count_human_usage(&monkey_map), 0);
+
+    let monkey2_value = monkey2.eval(&monkey_map);
+    let human_value = monkey1.solve(monkey2_value, &monkey_map);
+    if cfg!(not(test)) {
+        let mut monkey_map = monkey_map.clone();
+        monkey_map.insert(HUMAN_MONKEY.to_string(), MonkeyRule::Number(human_value));
+        assert_eq!(monkey2_value, monkey1.eval(&monkey_map));
+    }
+    Ok(human_value)
+}
+
+/// Day 21 in the common [`Solution`] format
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Params = ();
+
+    fn part1(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        solve_part1(file_name).map(Into::into)
+    }
+
+    fn part2(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        solve_part2(file_name).map(Into::into)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Operation {
+    fn op_name(&self) -> String {
+        match self {
+            Operation::Add => "+".to_string(),
+            Operation::Sub => "-".to_string(),
+            Operation::Mul => "*".to_string(),
+            Operation::Div => "/".to_string(),
+        }
+    }
+
+    fn eval(&self, value1: isize, value2: isize) -> isize {
+        match self {
+            Operation::Add => value1 + value2,
+            Operation::Sub => value1 - value2,
+            Operation::Mul => value1 * value2,
+            Operation::Div => value1 / value2,
+        }
+    }
+
+    fn eval_inverse_right(&self, value1: isize, value2: isize) -> isize {
+        match self {
+            Operation::Add => value1 - value2,
+            Operation::Sub => value1 + value2,
+            Operation::Mul => value1 / value2,
+            Operation::Div => value1 * value2,
+        }
+    }
+
+    fn eval_inverse_left(&self, value1: isize, value2: isize) -> isize {
+        match self {
+            Operation::Add => value1 - value2,
+            Operation::Sub => value2 - value1,
+            Operation::Mul => value1 / value2,
+            Operation::Div => value2 / value1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, IsVariant, Unwrap)]
+enum MonkeyRule {
+    Number(isize),
+    Monkey(MonkeyIndex),
+    Human(MonkeyIndex),
+    Operation(Operation, Box<MonkeyRule>, Box<MonkeyRule>),
+}
+
+impl MonkeyRule {
+    fn count_human_usage(&self, monkey_map: &FxHashMap<MonkeyIndex, MonkeyRule>) -> isize {
+        if self.is_human() {
+            return 1;
+        }
+        self.get_used_monkeys_iter(monkey_map)
+            .map(|monkey| monkey.count_human_usage(monkey_map))
+            .sum()
+    }
+
+    fn get_used_monkeys_iter<'a>(
+        &self,
+        monkey_map: &'a FxHashMap<MonkeyIndex, MonkeyRule>,
+    ) -> impl Iterator<Item = &'a MonkeyRule> {
+        fn get_used_monkey_names(rule: &MonkeyRule) -> impl Iterator<Item = &MonkeyIndex> {
+            match rule {
+                MonkeyRule::Number(_) => vec![].into_iter(),
+                MonkeyRule::Monkey(monkey_name) | MonkeyRule::Human(monkey_name) => {
+                    vec![monkey_name].into_iter()
+                }
+                MonkeyRule::Operation(_, rule1, rule2) => {
+                    let x = get_used_monkey_names(rule1)
+                        .chain(get_used_monkey_names(rule2))
+                        .collect_vec();
+                    x.into_iter()
+                }
+            }
+        }
+
+        get_used_monkey_names(self)
+            .map(|monkey_name| monkey_map.get(monkey_name).unwrap())
+            .collect_vec()
+            .into_iter()
+    }
+
+    fn eval(&self, monkey_map: &FxHashMap<MonkeyIndex, MonkeyRule>) -> isize {
+        match self {
+            MonkeyRule::Number(n) => *n,
+            MonkeyRule::Operation(op, rule1, rule2) => {
+                op.eval(rule1.eval(monkey_map), rule2.eval(monkey_map))
+            }
+            MonkeyRule::Monkey(monkey_name) => {
+                monkey_map.get(monkey_name).unwrap().eval(monkey_map)
+            }
+            MonkeyRule::Human(_) => panic!("Can't eval human, need to solve first"),
+        }
+    }
+
+    fn depends_on_human(&self, monkey_map: &FxHashMap<MonkeyIndex, MonkeyRule>) -> bool {
+        match self {
+            MonkeyRule::Number(_) => false,
+            MonkeyRule::Human(_) => true,
+            MonkeyRule::Monkey(monkey_name) => monkey_map
+                .get(monkey_name)
+                .unwrap()
+                .depends_on_human(monkey_map),
+            MonkeyRule::Operation(_, rule1, rule2) => {
+                rule1.depends_on_human(monkey_map) || rule2.depends_on_human(monkey_map)
+            }
+        }
+    }
+
+    pub fn solve(&self, value: isize, monkey_map: &FxHashMap<MonkeyIndex, MonkeyRule>) -> isize {
+        match self {
+            MonkeyRule::Number(n) => *n,
+            MonkeyRule::Human(_) => value,
+            MonkeyRule::Monkey(monkey_name) => monkey_map
+                .get(monkey_name)
+                .unwrap()
+                .solve(value, monkey_map),
+            MonkeyRule::Operation(op, rule1, rule2) => {
+                if rule1.depends_on_human(monkey_map) {
+                    rule1.solve(
+                        op.eval_inverse_right(value, rule2.eval(monkey_map)),
+                        monkey_map,
+                    )
+                } else {
+                    assert!(
+                        !rule1.depends_on_human(monkey_map) && rule2.depends_on_human(monkey_map)
+                    );
+                    rule2.solve(
+                        op.eval_inverse_left(value, rule1.eval(monkey_map)),
+                        monkey_map,
+                    )
+                }
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+fn parse(file_name: &str) -> InputResult<FxHashMap<String, MonkeyRule>> {
+    let mut monkey_map = FxHashMap::default();
+    for (line_index, line) in utils::try_file_to_lines(file_name)?.enumerate() {
+        let line = line?;
+        let (name, operation) =
+            parse_line(&line).map_err(|error| error.at_line(line_index + 1).in_file(file_name))?;
+        monkey_map.insert(name, operation);
+    }
+    // every monkey used in a rule (and root) has to exist, otherwise eval would fail later
+    if let Some(unknown) = monkey_map
+        .values()
+        .filter_map(|rule| match rule {
+            MonkeyRule::Operation(_, monkey1, monkey2) => Some([monkey1, monkey2]),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|monkey| match monkey.as_ref() {
+            MonkeyRule::Monkey(name) => Some(name.as_str()),
+            _ => None,
+        })
+        .chain([ROOT_MONKEY])
+        .find(|name| !monkey_map.contains_key(*name))
+    {
+        return Err(InputError::parse("unknown monkey", unknown).in_file(file_name));
+    }
+    Ok(monkey_map)
+}
+
+/// Parses "root: pppw + sjmn" or "dbpl: 5"
+fn parse_line(line: &str) -> InputResult<(String, MonkeyRule)> {
+    let (name, operation) = line
+        .split_once(": ")
+        .ok_or_else(|| InputError::parse("expected \"<name>: <rule>\"", line))?;
+    let rule = MonkeyRule::from_str(operation).map_err(|error| error.in_line(line, operation))?;
+    Ok((name.to_string(), rule))
+}
+
+impl FromStr for MonkeyRule {
+    type Err = InputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        #[allow(clippy::unnecessary_box_returns)]
+        fn create_monkey(monkey: &str) -> Box<MonkeyRule> {
+            Box::new(MonkeyRule::Monkey(monkey.trim().to_string()))
+        }
+
+        [
+            Operation::Add,
+            Operation::Sub,
+            Operation::Mul,
+            Operation::Div,
+        ]
+        .into_iter()
+        .find(|op| s.contains(&op.op_name()))
+        .map_or_else(
+            || {
+                utils::try_str_to(s)
+                    .map(MonkeyRule::Number)
+                    .map_err(|error| error.in_line(s, s))
+            },
+            |op| {
+                let (monkey1, monkey2) = s.split_once(&op.op_name()).unwrap();
+                Ok(MonkeyRule::Operation(
+                    op,
+                    create_monkey(monkey1),
+                    create_monkey(monkey2),
+                ))
+            },
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve_part1("test.txt").unwrap(), 152);
+    }
+
+    #[test]
+    fn verify1() {
+        assert_eq!(solve_part1("input.txt").unwrap(), 80326079210554);
+    }
+
+    #[test]
+    fn invalid_rule() {
+        assert_eq!(
+            parse_line("dbpl: 5x").unwrap_err().to_string(),
+            "col 7: invalid digit found in string: \"5x\""
+        );
+        assert_eq!(
+            parse_line("dbpl 5").unwrap_err().to_string(),
+            "expected \"<name>: <rule>\": \"dbpl 5\""
+        );
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve_part2("test.txt").unwrap(), 301);
+    }
+
+    #[test]
+    fn verify2() {
+        assert_eq!(solve_part2("input.txt").unwrap(), 3617613952378);
+    }
+}
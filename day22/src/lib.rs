@@ -0,0 +1,288 @@
+#![allow(unused_imports)]
+#![allow(dead_code)]
+#![allow(unused_must_use)]
+//#![deny(clippy::all, clippy::pedantic)]
+#![allow(
+    clippy::enum_glob_use,
+    clippy::many_single_char_names,
+    clippy::must_use_candidate
+)]
+#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::doc_markdown)]
+#![allow(clippy::unreadable_literal)]
+
+use grid::{
+    grid_array::{GridArray, GridArrayBuilder},
+    grid_types::{Coor2D, Direction, Neighborhood, Topology},
+};
+use itertools::Itertools;
+
+use utils::{Answer, InputResult, Solution};
+
+////////////////////////////////////////////////////////////////////////////////////
+
+type UInt = u16;
+
+pub fn solve_part1(file_name: &str) -> usize {
+    let (grid, path) = utils::bench::parse_phase(|| parse(file_name));
+    let mut turtle = Turtle::new(grid);
+
+    for (command, amount) in &path {
+        turtle.apply_command(command);
+        for _index in 0..*amount {
+            turtle.move_forward();
+        }
+    }
+
+    turtle.calc_result()
+}
+
+pub fn solve_part2(file_name: &str) -> usize {
+    let (grid, path) = utils::bench::parse_phase(|| parse(file_name));
+    let grid = grid.with_cube_net(|ch| ch != &' ').unwrap();
+    let mut turtle = Turtle::new(grid);
+
+    for (command, amount) in &path {
+        turtle.apply_command(command);
+        for _index in 0..*amount {
+            turtle.move_forward_part2();
+        }
+    }
+
+    turtle.calc_result()
+}
+
+/// Day 22 in the common [`Solution`] format
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    type Params = ();
+
+    fn part1(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part1(file_name).into())
+    }
+
+    fn part2(file_name: &str, _params: &Self::Params) -> InputResult<Answer> {
+        Ok(solve_part2(file_name).into())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(u8)]
+enum Command {
+    Left,
+    Right,
+    Straight,
+}
+
+#[derive(Debug, Clone)]
+struct Turtle {
+    curr_pos: Coor2D,
+    curr_dir: Direction,
+    grid: GridArray<char>,
+}
+
+impl Turtle {
+    fn new(grid: GridArray<char>) -> Self {
+        // leftmost open tile of the top row
+        let start_x = (0..grid.get_width())
+            .find(|x| grid.get_unchecked(*x, 0) == &'.')
+            .unwrap();
+        Turtle {
+            curr_pos: Coor2D::new(start_x, 0),
+            curr_dir: Direction::East,
+            grid,
+        }
+    }
+
+    fn calc_result(&self) -> usize {
+        1000 * (self.curr_pos.y + 1)
+            + 4 * (self.curr_pos.x + 1)
+            + match self.curr_dir {
+                Direction::East => 0,
+                Direction::South => 1,
+                Direction::West => 2,
+                Direction::North => 3,
+                _ => unreachable!(),
+            }
+    }
+
+    // going over an edge of the cube face continues on the adjacent face (with a new direction)
+    fn move_forward_part2(&mut self) {
+        let (next_pos, next_dir) = self
+            .grid
+            .adjacent_cell_with_direction(self.curr_pos.x, self.curr_pos.y, self.curr_dir)
+            .unwrap();
+
+        // check for wall
+        if self.grid.get_unchecked(next_pos.x, next_pos.y) != &'#' {
+            self.curr_pos = next_pos;
+            self.curr_dir = next_dir;
+        }
+
+        debug_assert!(
+            self.grid.get_unchecked(self.curr_pos.x, self.curr_pos.y) == &'.',
+            "AFTER MOVE '{}' {:?}",
+            self.grid.get_unchecked(self.curr_pos.x, self.curr_pos.y),
+            self
+        );
+    }
+
+    fn move_forward(&mut self) {
+        let next_pos = self._get_next_pos();
+
+        // check for wall
+        if self.grid.get_unchecked(next_pos.x, next_pos.y) != &'#' {
+            self.curr_pos = next_pos;
+        }
+
+        debug_assert!(
+            self.grid.get_unchecked(self.curr_pos.x, self.curr_pos.y) == &'.',
+            "AFTER MOVE '{}' {:?}",
+            self.grid.get_unchecked(self.curr_pos.x, self.curr_pos.y),
+            self
+        );
+    }
+
+    // going over the edge of the grid will wrap around and also ignoring empty cells
+    fn _get_next_pos(&mut self) -> Coor2D {
+        let mut next_pos = self.curr_pos.clone();
+        next_pos = self
+            .grid
+            .adjacent_cell(next_pos.x, next_pos.y, self.curr_dir)
+            .unwrap();
+        while self.grid.get_unchecked(next_pos.x, next_pos.y) == &' ' {
+            next_pos = self
+                .grid
+                .adjacent_cell(next_pos.x, next_pos.y, self.curr_dir)
+                .unwrap();
+        }
+        next_pos
+    }
+
+    fn apply_command(&mut self, cmd: &Command) {
+        match (self.curr_dir, cmd) {
+            (Direction::North, Command::Left) => self.curr_dir = Direction::West,
+            (Direction::North, Command::Right) => self.curr_dir = Direction::East,
+            (Direction::South, Command::Left) => self.curr_dir = Direction::East,
+            (Direction::South, Command::Right) => self.curr_dir = Direction::West,
+            (Direction::East, Command::Left) => self.curr_dir = Direction::North,
+            (Direction::East, Command::Right) => self.curr_dir = Direction::South,
+            (Direction::West, Command::Left) => self.curr_dir = Direction::South,
+            (Direction::West, Command::Right) => self.curr_dir = Direction::North,
+            (_, Command::Straight) => {}
+            (Direction::NorthEast, _) => unreachable!("NorthEast is not a valid direction"),
+            (Direction::SouthEast, _) => unreachable!("SouthEast is not a valid direction"),
+            (Direction::NorthWest, _) => unreachable!("NorthWest is not a valid direction"),
+            (Direction::SouthWest, _) => unreachable!("SouthWest is not a valid direction"),
+        }
+    }
+
+    fn print_grid(&self) {
+        for y in 0..self.grid.get_height() {
+            print!("|");
+            for x in 0..self.grid.get_width() {
+                if self.curr_pos.to_tuple() == (x, y) {
+                    print!(
+                        "{}",
+                        match self.curr_dir {
+                            Direction::North => '^',
+                            Direction::South => 'v',
+                            Direction::East => '>',
+                            Direction::West => '<',
+                            _ => unreachable!(),
+                        }
+                    );
+                } else {
+                    print!("{}", self.grid.get_unchecked(x, y));
+                }
+            }
+            println!("|");
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+fn parse(file_name: &str) -> (GridArray<char>, Vec<(Command, u16)>) {
+    let input = utils::file_to_string(file_name).replace("\r\n", "\n");
+    let (board, path_str) = input.split_once("\n\n").unwrap();
+    let grid_vec = board
+        .lines()
+        .map(|line| line.chars().collect_vec())
+        .collect_vec();
+    let mut grid: GridArray<char> = GridArrayBuilder::default()
+        .topology(Topology::Torus)
+        .neighborhood(Neighborhood::Orthogonal)
+        .width(grid_vec.iter().map(|x| x.len()).max().unwrap())
+        .height(grid_vec.len())
+        .build()
+        .unwrap();
+    #[allow(clippy::needless_range_loop)]
+    for y in 0..grid.get_height() {
+        for x in 0..grid.get_width() {
+            grid.set(x, y, *grid_vec[y].get(x).unwrap_or(&' '));
+        }
+    }
+    let alpha_indexes = path_str
+        .chars()
+        .enumerate()
+        .filter(|(_, c)| c.is_alphabetic())
+        .map(|(index, _)| index)
+        .chain(vec![path_str.len()])
+        .collect_vec();
+    let mut path: Vec<(Command, UInt)> = alpha_indexes
+        .iter()
+        .tuple_windows()
+        .map(|(index_start, index_end)| {
+            (
+                match path_str.chars().nth(*index_start).unwrap() {
+                    'L' => Command::Left,
+                    'R' => Command::Right,
+                    _ => panic!("Unknown direction"),
+                },
+                utils::str_to(&path_str[index_start + 1..*index_end]),
+            )
+        })
+        .collect_vec();
+    path.insert(
+        0,
+        (
+            Command::Straight,
+            path_str[0..alpha_indexes[0]].parse::<UInt>().unwrap(),
+        ),
+    );
+    (grid, path)
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve_part1("test.txt"), 6032);
+    }
+
+    #[test]
+    fn verify1() {
+        assert_eq!(solve_part1("input.txt"), 27492);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve_part2("test.txt"), 5031);
+    }
+
+    #[test]
+    fn verify2() {
+        assert_eq!(solve_part2("input.txt"), 78291);
+    }
+}
@@ -0,0 +1,189 @@
+//! Rasterised lines, polylines, rectangles and Manhattan diamonds as iterators over their cells,
+//! [`draw`] writes them into any [`Grid`]
+
+use crate::grid_trait::Grid;
+use crate::grid_types::Coor2DMut;
+
+/// Coor of the drawing helpers (signed, converted by [`draw`] to the coor of the grid)
+pub type Coor = Coor2DMut<isize>;
+
+/// Cells of the line from `from` to `to` (both included), None if they are neither in one row
+/// nor in one column
+pub fn axis_line(from: &Coor, to: &Coor) -> Option<impl Iterator<Item = Coor>> {
+    (from.x == to.x || from.y == to.y).then(|| line(from, to))
+}
+
+/// Cells of the Bresenham line from `from` to `to` (both included)
+pub fn line(from: &Coor, to: &Coor) -> impl Iterator<Item = Coor> {
+    let (dx, dy) = ((to.x - from.x).abs(), -(to.y - from.y).abs());
+    let (step_x, step_y) = ((to.x - from.x).signum(), (to.y - from.y).signum());
+    let mut error = dx + dy;
+    let to = to.clone();
+    let mut next = Some(from.clone());
+    std::iter::from_fn(move || {
+        let coor = next.take()?;
+        if coor != to {
+            let mut following = coor.clone();
+            let double_error = 2 * error;
+            if double_error >= dy {
+                error += dy;
+                following.x += step_x;
+            }
+            if double_error <= dx {
+                error += dx;
+                following.y += step_y;
+            }
+            next = Some(following);
+        }
+        Some(coor)
+    })
+}
+
+/// Cells of the lines between consecutive points (each cell once where the lines meet)
+pub fn polyline(points: impl IntoIterator<Item = Coor>) -> impl Iterator<Item = Coor> {
+    let points = points.into_iter().collect::<Vec<_>>();
+    let first = points.first().cloned();
+    first.into_iter().chain(
+        (1..points.len()).flat_map(move |index| line(&points[index - 1], &points[index]).skip(1)),
+    )
+}
+
+/// Cells of the border of the rectangle between `min` and `max` (both included, reading order)
+pub fn rectangle(min: &Coor, max: &Coor) -> impl Iterator<Item = Coor> {
+    let (min, max) = (min.clone(), max.clone());
+    filled_rectangle(&min, &max).filter(move |coor| {
+        coor.x == min.x || coor.x == max.x || coor.y == min.y || coor.y == max.y
+    })
+}
+
+/// All cells of the rectangle between `min` and `max` (both included, reading order)
+pub fn filled_rectangle(min: &Coor, max: &Coor) -> impl Iterator<Item = Coor> {
+    let (min_x, max_x) = (min.x, max.x);
+    (min.y..=max.y).flat_map(move |y| (min_x..=max_x).map(move |x| Coor::new(x, y)))
+}
+
+/// Cells with the manhattan distance `radius` to `center` (reading order)
+pub fn diamond(center: &Coor, radius: usize) -> impl Iterator<Item = Coor> {
+    let center = center.clone();
+    let radius = isize::try_from(radius).unwrap();
+    (-radius..=radius).flat_map(move |dy| {
+        let dx = radius - dy.abs();
+        let left = Coor::new(center.x - dx, center.y + dy);
+        let right = (dx > 0).then(|| Coor::new(center.x + dx, center.y + dy));
+        std::iter::once(left).chain(right)
+    })
+}
+
+/// Cells with at most the manhattan distance `radius` to `center` (reading order)
+pub fn filled_diamond(center: &Coor, radius: usize) -> impl Iterator<Item = Coor> {
+    let center = center.clone();
+    let radius = isize::try_from(radius).unwrap();
+    (-radius..=radius).flat_map(move |dy| {
+        let dx = radius - dy.abs();
+        let (x, y) = (center.x, center.y + dy);
+        (x - dx..=x + dx).map(move |x| Coor::new(x, y))
+    })
+}
+
+/// Sets the given cells of a grid to `value` (cells outside of the grid are skipped),
+/// returns the number of set cells
+pub fn draw<G: Grid>(grid: &mut G, coors: impl IntoIterator<Item = Coor>, value: &G::Cell) -> usize
+where
+    G::Cell: Clone,
+{
+    let mut count = 0;
+    for coor in coors {
+        if let Some(coor) = G::from_xy(coor.x, coor.y).filter(|coor| grid.contains(coor)) {
+            grid.set_cell(coor, value.clone());
+            count += 1;
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_array::GridArray;
+    use crate::grid_hashmap::GridHashMap;
+    use crate::grid_types::{Neighborhood, Topology};
+
+    fn coor(x: isize, y: isize) -> Coor {
+        Coor::new(x, y)
+    }
+
+    fn tuples(coors: impl Iterator<Item = Coor>) -> Vec<(isize, isize)> {
+        coors.map(|coor| coor.to_tuple()).collect()
+    }
+
+    #[test]
+    fn lines() {
+        assert_eq!(
+            tuples(axis_line(&coor(3, 1), &coor(0, 1)).unwrap()),
+            vec![(3, 1), (2, 1), (1, 1), (0, 1)]
+        );
+        assert!(axis_line(&coor(0, 0), &coor(1, 1)).is_none());
+        assert_eq!(tuples(line(&coor(2, 2), &coor(2, 2))), vec![(2, 2)]);
+        assert_eq!(
+            tuples(line(&coor(0, 0), &coor(-2, 2))),
+            vec![(0, 0), (-1, 1), (-2, 2)]
+        );
+        assert_eq!(
+            tuples(line(&coor(0, 0), &coor(4, 2))),
+            vec![(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)]
+        );
+        // every step goes to a neighbor, one cell per step along the longer axis
+        for (x, y) in [(7, 3), (-3, 7), (-7, -2), (1, -6)] {
+            let cells = line(&coor(0, 0), &coor(x, y)).collect::<Vec<_>>();
+            assert_eq!(cells.len(), x.unsigned_abs().max(y.unsigned_abs()) + 1);
+            assert_eq!(cells.last(), Some(&coor(x, y)));
+            assert!(cells.windows(2).all(
+                |pair| (pair[1].x - pair[0].x).abs() <= 1 && (pair[1].y - pair[0].y).abs() <= 1
+            ));
+        }
+
+        // rock path of day 14
+        let path = [coor(498, 4), coor(498, 6), coor(496, 6)];
+        assert_eq!(
+            tuples(polyline(path)),
+            vec![(498, 4), (498, 5), (498, 6), (497, 6), (496, 6)]
+        );
+        assert_eq!(polyline([]).count(), 0);
+    }
+
+    #[test]
+    fn shapes() {
+        assert_eq!(rectangle(&coor(0, 0), &coor(2, 2)).count(), 8);
+        assert_eq!(rectangle(&coor(0, 0), &coor(3, 0)).count(), 4);
+        assert_eq!(filled_rectangle(&coor(-1, -1), &coor(1, 0)).count(), 6);
+        assert_eq!(
+            tuples(diamond(&coor(0, 0), 1)),
+            vec![(0, -1), (-1, 0), (1, 0), (0, 1)]
+        );
+        assert_eq!(diamond(&coor(5, 5), 0).count(), 1);
+        assert_eq!(diamond(&coor(5, 5), 3).count(), 12);
+        assert_eq!(filled_diamond(&coor(5, 5), 2).count(), 13);
+        assert!(
+            filled_diamond(&coor(5, 5), 2).all(|cell| cell.manhattan_distance(&coor(5, 5)) <= 2)
+        );
+    }
+
+    #[test]
+    fn grids() {
+        let mut array = GridArray::from_newline_separated_string(
+            Topology::Bounded,
+            Neighborhood::Square,
+            "....\n....\n....\n",
+        );
+        assert_eq!(
+            draw(&mut array, rectangle(&coor(-1, 0), &coor(2, 2)), &'#'),
+            7
+        );
+        assert_eq!(array.to_string(), "###.\n..#.\n###.\n");
+
+        let mut hashmap: GridHashMap<char> =
+            GridHashMap::from_1d_vec(Neighborhood::Square, 1, vec![]);
+        assert_eq!(draw(&mut hashmap, filled_diamond(&coor(-5, 0), 1), &'o'), 5);
+        assert_eq!(hashmap.get(&coor(-6, 0)), Some(&'o'));
+    }
+}
@@ -2,18 +2,28 @@
 
 use std::fmt::Display;
 use std::mem::{replace, swap};
+use std::str::FromStr;
 
 use itertools::Itertools;
 
+use crate::grid_cube::CubeNet;
+use crate::grid_diff::{CellChange, ChangeJournal, GridDiff};
 use crate::grid_iteration::{adjacent_cell, is_corner, is_edge};
+use crate::grid_text::{self, ParseGridError};
 use crate::grid_types::Direction;
+use crate::grid_view::GridView;
 
 use super::grid_iteration;
 use super::grid_types::{Coor2D, Coor2DIndex, Neighborhood, Topology};
 
+/// Journal of an array grid with the check for empty (default) cells
+type Journal<T> = (ChangeJournal<Coor2D, T>, fn(&T) -> bool);
+
 /// `GridArray`
 #[allow(missing_docs)]
-#[derive(Builder, Clone, PartialEq, Debug)]
+#[derive(Builder, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "GridArrayFields<T>"))]
 pub struct GridArray<T: Default + Clone + std::fmt::Display> {
     /// width of the grid
     width: Coor2DIndex,
@@ -27,6 +37,12 @@ pub struct GridArray<T: Default + Clone + std::fmt::Display> {
     //    #[builder(setter(skip), default = "self.create_data_vec()")]
     #[builder(default = "self.create_data_vec()")]
     data: Vec<T>,
+
+    /// records the cells changed by `set` (see `start_journal`), ignored by `eq` and not cloned,
+    /// with the check for empty (default) cells that are recorded as None
+    #[builder(setter(skip))]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    journal: Option<Journal<T>>,
 }
 
 impl<T: Default + Clone + std::fmt::Display> GridArrayBuilder<T> {
@@ -35,6 +51,67 @@ impl<T: Default + Clone + std::fmt::Display> GridArrayBuilder<T> {
     }
 }
 
+/// Deserialized fields of a `GridArray`, the size is checked before they become a grid
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct GridArrayFields<T> {
+    width: Coor2DIndex,
+    height: Coor2DIndex,
+    topology: Topology,
+    neighborhood: Neighborhood,
+    data: Vec<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T: Default + Clone + std::fmt::Display> TryFrom<GridArrayFields<T>> for GridArray<T> {
+    type Error = String;
+
+    fn try_from(fields: GridArrayFields<T>) -> Result<Self, String> {
+        if fields.width.checked_mul(fields.height) != Some(fields.data.len()) {
+            return Err(format!(
+                "{} cells, expected {}x{}",
+                fields.data.len(),
+                fields.width,
+                fields.height
+            ));
+        }
+        Ok(GridArray {
+            width: fields.width,
+            height: fields.height,
+            topology: fields.topology,
+            neighborhood: fields.neighborhood,
+            data: fields.data,
+            journal: None,
+        })
+    }
+}
+
+/// A clone has no journal (clones in a simulation would copy the whole history)
+impl<T: Default + Clone + std::fmt::Display> Clone for GridArray<T> {
+    fn clone(&self) -> Self {
+        GridArray {
+            width: self.width,
+            height: self.height,
+            topology: self.topology,
+            neighborhood: self.neighborhood,
+            data: self.data.clone(),
+            journal: None,
+        }
+    }
+}
+
+/// Grids are equal if they have the same size, topology, neighborhood and cells (the journal
+/// is ignored)
+impl<T: Default + Clone + std::fmt::Display + PartialEq> PartialEq for GridArray<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width
+            && self.height == other.height
+            && self.topology == other.topology
+            && self.neighborhood == other.neighborhood
+            && self.data == other.data
+    }
+}
+
 impl<T: Default + Clone + std::fmt::Display> Display for GridArray<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for y in 0..self.height {
@@ -71,6 +148,21 @@ impl GridArray<char> {
     }
 }
 
+/// Parses one cell per char with `T::from_str` (e.g. the digits of a `GridArray<u8>`), a bounded
+/// grid with the square neighborhood
+impl<T: Default + Clone + Display + FromStr> FromStr for GridArray<T>
+where
+    T::Err: Display,
+{
+    type Err = ParseGridError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        GridArray::parse_with(Topology::Bounded, Neighborhood::Square, input, |ch| {
+            ch.encode_utf8(&mut [0; 4]).parse()
+        })
+    }
+}
+
 impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
     #[allow(dead_code)]
     fn create_data_vec(&self) -> Vec<T> {
@@ -97,6 +189,7 @@ impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
             topology,
             neighborhood,
             data,
+            journal: None,
         }
     }
 
@@ -109,7 +202,30 @@ impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
             topology,
             neighborhood,
             data: data.into_iter().flatten().collect(),
+            journal: None,
+        }
+    }
+
+    /// Grid of the lines of input (all with the same number of chars), each char mapped to a cell
+    pub fn parse_with<E: Display>(
+        topology: Topology,
+        neighborhood: Neighborhood,
+        input: &str,
+        map: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseGridError> {
+        let rows = grid_text::parse_rows(input, map)?;
+        let Some(width) = rows.first().map(Vec::len).filter(|width| *width > 0) else {
+            return Err(ParseGridError::new(1, 1, input, "no cells"));
+        };
+        if let Some((y, row)) = rows.iter().find_position(|row| row.len() != width) {
+            return Err(ParseGridError::new(
+                y + 1,
+                row.len().min(width) + 1,
+                input.lines().nth(y).unwrap(),
+                format!("{} cells, expected {width}", row.len()),
+            ));
         }
+        Ok(GridArray::from_2d_vec(topology, neighborhood, rows))
     }
 
     #[allow(unused_comparisons)]
@@ -203,11 +319,20 @@ impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
 
     fn set_unchecked(&mut self, x: usize, y: usize, new_value: T) -> T {
         let vec_index = self.index_to_vec_index(x, y);
+        if let Some((journal, is_empty)) = &mut self.journal {
+            let is_empty = *is_empty;
+            let cell = |cell: &T| (!is_empty(cell)).then(|| cell.clone());
+            journal.record(CellChange {
+                coor: Coor2D::new(x, y),
+                old: cell(&self.data[vec_index]),
+                new: cell(&new_value),
+            });
+        }
         replace(&mut self.data[vec_index], new_value)
     }
 
     /// set new element on x, y based on vector
-    pub fn set_from_vec(&mut self, new_values: &Vec<Vec<T>>) {
+    pub fn set_from_vec(&mut self, new_values: &[Vec<T>]) {
         debug_assert_eq!(new_values.len(), self.height);
         debug_assert_eq!(new_values[0].len(), self.width);
         for (y, row) in new_values.iter().enumerate() {
@@ -281,6 +406,30 @@ impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
         )
     }
 
+    /// return adjacent cell in direction and the direction after the step (only changes when
+    /// stepping over an edge of a cube net)
+    pub fn adjacent_cell_with_direction(
+        &self,
+        x: Coor2DIndex,
+        y: Coor2DIndex,
+        direction: Direction,
+    ) -> Option<(Coor2D, Direction)> {
+        match self.topology {
+            Topology::CubeNet(net) => net.adjacent_cell(&Coor2D::new(x, y), direction),
+            _ => Some((self.adjacent_cell(x, y, direction)?, direction)),
+        }
+    }
+
+    /// The grid with the topology of the cube net formed by the cells for which `is_face` is
+    /// true (face size and folding are detected), None if they are not a net of a cube
+    pub fn with_cube_net(mut self, is_face: impl Fn(&T) -> bool) -> Option<Self> {
+        let net = CubeNet::detect(self.width, self.height, |x, y| {
+            is_face(self.get_unchecked(x, y))
+        })?;
+        self.topology = Topology::CubeNet(net);
+        Some(self)
+    }
+
     /// Print grid
     pub fn print(&self, add_stars: bool) {
         for y in 0..self.height {
@@ -321,7 +470,7 @@ impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
         }
     }
 
-    fn _transform(&mut self, coors: impl Iterator<Item = Coor2D>, swap_width_height: bool) {
+    fn transform(&mut self, coors: impl Iterator<Item = Coor2D>, swap_width_height: bool) {
         let new_data = coors
             .map(|coor| self.get_unchecked(coor.x, coor.y))
             .cloned()
@@ -334,7 +483,7 @@ impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
 
     /// transpose
     pub fn transpose(&mut self) {
-        self._transform(
+        self.transform(
             (0..self.width)
                 .cartesian_product(0..self.height)
                 .map(Coor2D::from_tuple),
@@ -345,7 +494,7 @@ impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
     /// rotate_cw
     pub fn rotate_cw(&mut self) {
         // rotate clockwise by 90°
-        self._transform(
+        self.transform(
             (0..self.width)
                 .cartesian_product((0..self.height).rev())
                 .map(Coor2D::from_tuple),
@@ -356,7 +505,7 @@ impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
     /// rotate_ccw
     pub fn rotate_ccw(&mut self) {
         // rotate counter clockwise by 90°
-        self._transform(
+        self.transform(
             ((0..self.width).rev())
                 .cartesian_product(0..self.height)
                 .map(Coor2D::from_tuple),
@@ -365,6 +514,270 @@ impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
     }
 }
 
+/// Differences and the change journal (see [`crate::grid_diff`]), an empty cell has the default
+/// value and is None in both (the journal records every set cell as changed)
+impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
+    /// Changes from this grid to other (the cells outside of a smaller grid are empty)
+    pub fn diff(&self, other: &Self) -> GridDiff<Coor2D, T>
+    where
+        T: PartialEq,
+    {
+        let empty = T::default();
+        let cell = |grid: &Self, x, y| grid.get(x, y).filter(|cell| **cell != empty).cloned();
+        let mut diff = GridDiff::new();
+        for y in 0..self.height.max(other.height) {
+            for x in 0..self.width.max(other.width) {
+                let (old, new) = (cell(self, x, y), cell(other, x, y));
+                if old != new {
+                    diff.push(CellChange {
+                        coor: Coor2D::new(x, y),
+                        old,
+                        new,
+                    });
+                }
+            }
+        }
+        diff
+    }
+
+    /// Sets the new cells of the changes (e.g. the steps of a journal to replay them)
+    pub fn apply_diff(&mut self, diff: &GridDiff<Coor2D, T>) {
+        for change in diff.changes() {
+            let new = change.new.clone().unwrap_or_default();
+            self.set(change.coor.x, change.coor.y, new);
+        }
+    }
+
+    /// Starts recording the cells changed by `set` (in steps, see `end_step`)
+    pub fn start_journal(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.journal = Some((ChangeJournal::new(), |cell| *cell == T::default()));
+    }
+
+    /// Stops recording and returns the journal
+    pub fn take_journal(&mut self) -> Option<ChangeJournal<Coor2D, T>> {
+        self.journal.take().map(|(journal, _)| journal)
+    }
+
+    /// `get_journal`
+    pub fn get_journal(&self) -> Option<&ChangeJournal<Coor2D, T>> {
+        self.journal.as_ref().map(|(journal, _)| journal)
+    }
+
+    /// Ends the current step of the journal
+    pub fn end_step(&mut self) {
+        if let Some((journal, _)) = &mut self.journal {
+            journal.end_step();
+        }
+    }
+
+    /// Reverts and removes the current step if it has changes, otherwise the last ended step of
+    /// the journal, returns the reverted step
+    pub fn undo_step(&mut self) -> Option<GridDiff<Coor2D, T>> {
+        let (mut journal, is_empty) = self.journal.take()?;
+        let step = journal.pop_step();
+        if let Some(step) = &step {
+            self.apply_diff(&step.reversed());
+        }
+        self.journal = Some((journal, is_empty));
+        step
+    }
+}
+
+/// Borrowed views of rectangles of the grid (see [`GridView`])
+impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
+    /// View of the rectangle with the size `width` x `height` starting at `x0`, `y0`
+    /// #Panics panics if the rectangle is not inside the grid
+    pub fn view(
+        &self,
+        x0: Coor2DIndex,
+        y0: Coor2DIndex,
+        width: Coor2DIndex,
+        height: Coor2DIndex,
+    ) -> GridView<'_, T> {
+        GridView::new(self, x0, y0, width, height)
+    }
+
+    /// Splits the grid into tiles of `tile_width` x `tile_height` (reading order), each with the
+    /// coor of the tile (e.g. 1, 0 is the second tile of the first row)
+    /// #Panics panics if the grid can not be split into tiles of that size
+    pub fn tiles(
+        &self,
+        tile_width: Coor2DIndex,
+        tile_height: Coor2DIndex,
+    ) -> impl Iterator<Item = (Coor2D, GridView<'_, T>)> {
+        assert!(
+            self.width.is_multiple_of(tile_width) && self.height.is_multiple_of(tile_height),
+            "{}x{} can not be split into tiles of {tile_width}x{tile_height}",
+            self.width,
+            self.height
+        );
+        let tiles_x = self.width / tile_width;
+        (0..self.height / tile_height)
+            .flat_map(move |y| (0..tiles_x).map(move |x| Coor2D::new(x, y)))
+            .map(move |coor| {
+                let view = self.view(
+                    coor.x * tile_width,
+                    coor.y * tile_height,
+                    tile_width,
+                    tile_height,
+                );
+                (coor, view)
+            })
+    }
+}
+
+/// Rows, columns and anti-diagonals, shifting and rotating single rows and columns (e.g. for
+/// sliding tile and tilt puzzles), the shifted and rotated cells are recorded in the journal
+impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
+    /// row y
+    pub fn row(&self, y: Coor2DIndex) -> &[T] {
+        debug_assert!(y < self.height, "y={y} height={}", self.height);
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    /// mutable row y (the changes are not recorded in the journal, like `get_mut`)
+    pub fn row_mut(&mut self, y: Coor2DIndex) -> &mut [T] {
+        debug_assert!(y < self.height, "y={y} height={}", self.height);
+        &mut self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    /// all rows from top to bottom (none if the grid is empty)
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.data.chunks(self.width.max(1))
+    }
+
+    /// cells of column x from top to bottom
+    pub fn col(&self, x: Coor2DIndex) -> impl Iterator<Item = &T> {
+        debug_assert!(x < self.width, "x={x} width={}", self.width);
+        // without width there are no cells, step_by panics for 0
+        self.data.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    /// all columns from left to right
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.col(x))
+    }
+
+    /// All anti-diagonals (cells with the same x + y) starting in the upper left corner, each
+    /// from the upper right to the lower left cell (none if the grid is empty)
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = (Coor2D, &T)>> {
+        let count = if self.data.is_empty() {
+            0
+        } else {
+            self.width + self.height - 1
+        };
+        (0..count).map(|sum| {
+            let min_y = sum.saturating_sub(self.width - 1);
+            let max_y = sum.min(self.height - 1);
+            self.map_indexes_to_cells((min_y..=max_y).map(move |y| Coor2D::new(sum - y, y)))
+        })
+    }
+
+    /// Rotates row y by offset cells to the East (West if negative), the cells moved over the
+    /// edge come back on the other side
+    pub fn rotate_row(&mut self, y: Coor2DIndex, offset: isize) {
+        let row = moved_line(self.row(y).to_vec(), offset, true);
+        self.set_line((0..self.width).map(|x| Coor2D::new(x, y)), row);
+    }
+
+    /// Shifts row y by offset cells to the East (West if negative), the cells moved over the
+    /// edge are dropped and the emptied cells get the default value
+    pub fn shift_row(&mut self, y: Coor2DIndex, offset: isize) {
+        let row = moved_line(self.row(y).to_vec(), offset, false);
+        self.set_line((0..self.width).map(|x| Coor2D::new(x, y)), row);
+    }
+
+    /// Rotates column x by offset cells to the South (North if negative), the cells moved over
+    /// the edge come back on the other side
+    pub fn rotate_col(&mut self, x: Coor2DIndex, offset: isize) {
+        let col = moved_line(self.col(x).cloned().collect(), offset, true);
+        self.set_line((0..self.height).map(|y| Coor2D::new(x, y)), col);
+    }
+
+    /// Shifts column x by offset cells to the South (North if negative), the cells moved over
+    /// the edge are dropped and the emptied cells get the default value
+    pub fn shift_col(&mut self, x: Coor2DIndex, offset: isize) {
+        let col = moved_line(self.col(x).cloned().collect(), offset, false);
+        self.set_line((0..self.height).map(|y| Coor2D::new(x, y)), col);
+    }
+
+    fn set_line(&mut self, coors: impl Iterator<Item = Coor2D>, cells: Vec<T>) {
+        for (coor, cell) in coors.zip(cells) {
+            self.set_unchecked(coor.x, coor.y, cell);
+        }
+    }
+}
+
+/// line moved by offset cells to the end (to the start if negative), wrapped around or filled
+/// with default cells
+fn moved_line<T: Default + Clone>(mut line: Vec<T>, offset: isize, wrap: bool) -> Vec<T> {
+    let len = line.len();
+    if len == 0 {
+        return line;
+    }
+    if wrap {
+        let offset = offset.rem_euclid(isize::try_from(len).unwrap());
+        line.rotate_right(usize::try_from(offset).unwrap());
+    } else {
+        let count = offset.unsigned_abs().min(len);
+        if offset >= 0 {
+            line.rotate_right(count);
+            line[..count].fill(T::default());
+        } else {
+            line.rotate_left(count);
+            line[len - count..].fill(T::default());
+        }
+    }
+    line
+}
+
+/// Rays and line of sight, a ray walks from a cell in one direction until the edge
+/// (on a torus until it is back at the cell), the closures get the cells as (coor, value)
+impl<T: Default + Clone + std::fmt::Display> GridArray<T> {
+    /// All cells from the one next to `start` in direction to the edge
+    /// (on a torus around the grid, `start` is not included)
+    pub fn ray(&self, start: &Coor2D, direction: Direction) -> impl Iterator<Item = (Coor2D, &T)> {
+        let start = start.clone();
+        std::iter::successors(
+            self.adjacent_cell(start.x, start.y, direction),
+            move |coor| self.adjacent_cell(coor.x, coor.y, direction),
+        )
+        .take_while(move |coor| *coor != start)
+        .map(|coor| (coor.clone(), self.get_unchecked(coor.x, coor.y)))
+    }
+
+    /// Cells of the ray that can be seen from `start`: up to and including the first cell
+    /// matching `is_blocking` (the number of cells is the view distance)
+    pub fn visible_from(
+        &self,
+        start: &Coor2D,
+        direction: Direction,
+        mut is_blocking: impl FnMut((&Coor2D, &T)) -> bool,
+    ) -> impl Iterator<Item = (Coor2D, &T)> {
+        let mut blocked = false;
+        self.ray(start, direction).take_while(move |(coor, cell)| {
+            let visible = !blocked;
+            blocked = blocked || is_blocking((coor, cell));
+            visible
+        })
+    }
+
+    /// Can `start` be seen from the edge in direction (no cell of the ray matches `is_blocking`)
+    pub fn is_visible_from_edge(
+        &self,
+        start: &Coor2D,
+        direction: Direction,
+        mut is_blocking: impl FnMut((&Coor2D, &T)) -> bool,
+    ) -> bool {
+        !self
+            .ray(start, direction)
+            .any(|(coor, cell)| is_blocking((&coor, cell)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use itertools::Itertools;
@@ -487,6 +900,136 @@ mod tests {
         debug_assert_eq!(&new_a, a);
     }
 
+    #[test]
+    fn rays() {
+        let mut a: GridArray<isize> = build_common_bounded_array().build().unwrap();
+        populate_with_enumerated(&mut a);
+        // column-major: value of x, y is x * height + y
+        let start = Coor2D::new(1, 2);
+        let ray = |a: &GridArray<isize>, direction| {
+            a.ray(&start, direction)
+                .map(|(_, cell)| *cell)
+                .collect_vec()
+        };
+        assert_eq!(ray(&a, Direction::East), vec![12, 17]);
+        assert_eq!(ray(&a, Direction::North), vec![6, 5]);
+        assert_eq!(ray(&a, Direction::SouthWest), vec![3]);
+        assert_eq!(a.ray(&Coor2D::new(0, 0), Direction::West).count(), 0);
+
+        let visible = a
+            .visible_from(&start, Direction::South, |(_, cell)| *cell >= 8)
+            .map(|(coor, _)| coor)
+            .collect_vec();
+        assert_eq!(visible, vec![Coor2D::new(1, 3)]);
+        assert_eq!(
+            a.visible_from(&start, Direction::South, |_| false).count(),
+            2
+        );
+        assert!(a.is_visible_from_edge(&start, Direction::West, |(_, cell)| *cell > 2));
+        assert!(!a.is_visible_from_edge(&start, Direction::East, |(_, cell)| *cell > 2));
+
+        let mut t: GridArray<isize> = build_common_torus_array().build().unwrap();
+        populate_with_enumerated(&mut t);
+        assert_eq!(ray(&t, Direction::East), vec![12, 17, 2]);
+        assert_eq!(t.ray(&start, Direction::South).count(), t.height - 1);
+    }
+
+    #[test]
+    fn lines() {
+        let mut a: GridArray<isize> = build_common_bounded_array().build().unwrap();
+        populate_with_enumerated(&mut a);
+        // column-major: value of x, y is x * height + y
+        assert_eq!(a.rows().count(), a.height);
+        assert_eq!(a.rows().nth(1), Some(&[1, 6, 11, 16][..]));
+        assert_eq!(
+            a.cols().map(Iterator::sum::<isize>).collect_vec(),
+            vec![10, 35, 60, 85]
+        );
+        assert_eq!(a.col(2).copied().collect_vec(), vec![10, 11, 12, 13, 14]);
+
+        let diagonals = a
+            .diagonals()
+            .map(|diagonal| diagonal.map(|(_, cell)| *cell).collect_vec())
+            .collect_vec();
+        assert_eq!(diagonals.len(), a.width + a.height - 1);
+        assert_eq!(diagonals[0], vec![0]);
+        assert_eq!(diagonals[3], vec![15, 11, 7, 3]);
+        assert_eq!(diagonals[6], vec![18, 14]);
+        assert_eq!(diagonals[7], vec![19]);
+        assert_eq!(
+            a.diagonals()
+                .flatten()
+                .map(|(coor, _)| coor)
+                .sorted_by_key(|coor| (coor.x, coor.y))
+                .collect_vec(),
+            a.all_indexes()
+                .sorted_by_key(|coor| (coor.x, coor.y))
+                .collect_vec()
+        );
+
+        a.row_mut(4).reverse();
+        assert_eq!(a.row(4), &[19, 14, 9, 4]);
+    }
+
+    #[test]
+    fn lines_of_empty_grids() {
+        for (width, height) in [(0, 0), (0, 3), (3, 0)] {
+            let a: GridArray<isize> = build_common_array()
+                .width(width)
+                .height(height)
+                .build()
+                .unwrap();
+            assert_eq!(a.rows().count(), 0);
+            assert_eq!(a.cols().flatten().count(), 0);
+            assert_eq!(a.diagonals().count(), 0);
+        }
+
+        // moving the lines of a degenerate grid moves nothing
+        let mut no_width: GridArray<isize> =
+            build_common_array().width(0).height(3).build().unwrap();
+        no_width.rotate_row(1, 2);
+        no_width.shift_row(2, -1);
+        assert_eq!(no_width.row(1), &[] as &[isize]);
+        let mut no_height: GridArray<isize> =
+            build_common_array().width(3).height(0).build().unwrap();
+        no_height.rotate_col(1, -4);
+        no_height.shift_col(2, 1);
+        assert_eq!(no_height.col(1).count(), 0);
+    }
+
+    #[test]
+    fn shift_and_rotate_lines() {
+        let mut a: GridArray<isize> = build_common_bounded_array().build().unwrap();
+        populate_with_enumerated(&mut a);
+        let start = a.clone();
+
+        a.rotate_row(1, 1);
+        assert_eq!(a.row(1), &[16, 1, 6, 11]);
+        a.rotate_row(1, -5);
+        assert_eq!(a, start);
+        a.rotate_col(3, 7);
+        assert_eq!(a.col(3).copied().collect_vec(), vec![18, 19, 15, 16, 17]);
+        a.rotate_col(3, -2);
+        assert_eq!(a, start);
+
+        a.shift_row(0, -1);
+        assert_eq!(a.row(0), &[5, 10, 15, 0]);
+        a.shift_row(0, 2);
+        assert_eq!(a.row(0), &[0, 0, 5, 10]);
+        a.shift_col(1, 3);
+        assert_eq!(a.col(1).copied().collect_vec(), vec![0, 0, 0, 0, 6]);
+        a.shift_col(1, -9);
+        assert!(a.col(1).all(|cell| *cell == 0));
+
+        let mut a = start.clone();
+        a.start_journal();
+        a.rotate_row(2, 1);
+        a.shift_col(0, 1);
+        a.end_step();
+        assert_eq!(a.undo_step().unwrap().len(), a.width + a.height);
+        assert_eq!(a, start);
+    }
+
     #[test]
     fn grid_bounded_square_array_tests() {
         let mut a: GridArray<isize> = build_common_bounded_array()
@@ -0,0 +1,290 @@
+//! 3D grid based on a vector
+
+use std::mem::replace;
+
+use crate::grid_iteration;
+use crate::grid_regions::{self, Component, Components};
+use crate::grid_types::{Coor3D, Coor3DIndex, Neighborhood3D};
+
+/// `GridArray3D`
+#[allow(missing_docs)]
+#[derive(Builder, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GridArray3D<T: Default + Clone + std::fmt::Display> {
+    width: Coor3DIndex,
+    height: Coor3DIndex,
+    depth: Coor3DIndex,
+
+    #[builder(default = "Neighborhood3D::Face")]
+    neighborhood: Neighborhood3D,
+
+    #[builder(default = "self.create_data_vec()")]
+    data: Vec<T>,
+}
+
+impl<T: Default + Clone + std::fmt::Display> GridArray3DBuilder<T> {
+    fn create_data_vec(&self) -> Vec<T> {
+        vec![T::default(); self.width.unwrap() * self.height.unwrap() * self.depth.unwrap()]
+    }
+}
+
+impl<T: Default + Clone + std::fmt::Display> GridArray3D<T> {
+    /// from 1d vector (x changes fastest, then y, then z)
+    pub fn from_1d_vec(
+        neighborhood: Neighborhood3D,
+        width: Coor3DIndex,
+        height: Coor3DIndex,
+        data: Vec<T>,
+    ) -> Self {
+        debug_assert_eq!(
+            data.len() % (width * height),
+            0,
+            "data.len()={} width={} height={}",
+            data.len(),
+            width,
+            height
+        );
+        GridArray3D {
+            width,
+            height,
+            depth: data.len() / (width * height),
+            neighborhood,
+            data,
+        }
+    }
+
+    fn check_index(&self, coor: &Coor3D) -> bool {
+        coor.x < self.width && coor.y < self.height && coor.z < self.depth
+    }
+
+    fn index_to_vec_index(&self, coor: &Coor3D) -> usize {
+        debug_assert!(
+            self.check_index(coor),
+            "coor={} width={} height={} depth={}",
+            coor,
+            self.width,
+            self.height,
+            self.depth
+        );
+        (coor.z * self.height + coor.y) * self.width + coor.x
+    }
+
+    /// `get_width`
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    /// `get_height`
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    /// `get_depth`
+    pub fn get_depth(&self) -> usize {
+        self.depth
+    }
+
+    /// `get_neighborhood`
+    pub fn get_neighborhood(&self) -> Neighborhood3D {
+        self.neighborhood
+    }
+
+    /// get reference to element on coor
+    pub fn get(&self, coor: &Coor3D) -> Option<&T> {
+        if self.check_index(coor) {
+            Some(&self.data[self.index_to_vec_index(coor)])
+        } else {
+            None
+        }
+    }
+
+    /// get reference to element on coor
+    pub fn get_unchecked(&self, coor: &Coor3D) -> &T {
+        &self.data[self.index_to_vec_index(coor)]
+    }
+
+    /// get mutable reference element on coor
+    pub fn get_mut(&mut self, coor: &Coor3D) -> Option<&mut T> {
+        if self.check_index(coor) {
+            let vec_index = self.index_to_vec_index(coor);
+            Some(&mut self.data[vec_index])
+        } else {
+            None
+        }
+    }
+
+    /// set new element on coor and return old element
+    pub fn set(&mut self, coor: &Coor3D, new_value: T) -> T {
+        let vec_index = self.index_to_vec_index(coor);
+        replace(&mut self.data[vec_index], new_value)
+    }
+
+    /// return all indexes (x changes fastest, then y, then z)
+    pub fn all_indexes(&self) -> impl Iterator<Item = Coor3D> {
+        grid_iteration::all_cells_3d(self.width, self.height, self.depth)
+    }
+
+    /// return all neighbor indexes (based on neighborhood)
+    pub fn neighborhood_cell_indexes(&self, coor: &Coor3D) -> impl Iterator<Item = Coor3D> {
+        grid_iteration::neighborhood_cells_3d(
+            self.width,
+            self.height,
+            self.depth,
+            coor,
+            self.neighborhood,
+        )
+    }
+
+    fn map_indexes_to_cells(
+        &self,
+        it: impl Iterator<Item = Coor3D>,
+    ) -> impl Iterator<Item = (Coor3D, &T)> {
+        it.map(|coor| {
+            let cell = self.get_unchecked(&coor);
+            (coor, cell)
+        })
+    }
+
+    /// all data
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+
+    /// return all elements
+    pub fn all_cells(&self) -> impl Iterator<Item = (Coor3D, &T)> {
+        self.map_indexes_to_cells(self.all_indexes())
+    }
+
+    /// return all neighbor elements (based on neighborhood)
+    pub fn neighborhood_cells(&self, coor: &Coor3D) -> impl Iterator<Item = (Coor3D, &T)> {
+        self.map_indexes_to_cells(self.neighborhood_cell_indexes(coor))
+    }
+
+    /// Smallest box containing all cells matching `predicate` (None if no cell matches)
+    pub fn bounding_box(
+        &self,
+        mut predicate: impl FnMut((&Coor3D, &T)) -> bool,
+    ) -> Option<(Coor3D, Coor3D)> {
+        self.all_cells()
+            .filter(|(coor, cell)| predicate((coor, cell)))
+            .fold(None, |min_max, (coor, _)| match min_max {
+                None => Some((coor.clone(), coor)),
+                Some((min, max)) => Some((min.min(&coor), max.max(&coor))),
+            })
+    }
+
+    /// All cells matching `passable` that are reachable from `start` (None if `start` is not passable)
+    pub fn flood_fill(
+        &self,
+        start: &Coor3D,
+        mut passable: impl FnMut((&Coor3D, &T)) -> bool,
+    ) -> Option<Component<Coor3D>> {
+        if !passable((start, self.get(start)?)) {
+            return None;
+        }
+        let cells = grid_regions::flood_fill(start.clone(), |coor| {
+            self.neighborhood_cells(coor)
+                .filter(|(neighbor, cell)| passable((neighbor, cell)))
+                .map(|(neighbor, _)| neighbor)
+                .collect::<Vec<_>>()
+        });
+        Some(Component::new(0, cells))
+    }
+
+    /// Connected regions of the cells matching `predicate` (ids in order of their first cell)
+    pub fn connected_components(
+        &self,
+        mut predicate: impl FnMut((&Coor3D, &T)) -> bool,
+    ) -> Components<Coor3D> {
+        let mask = self
+            .all_cells()
+            .map(|(coor, cell)| predicate((&coor, cell)))
+            .collect::<Vec<_>>();
+        let matching = self
+            .all_indexes()
+            .filter(|coor| mask[self.index_to_vec_index(coor)]);
+        let components = grid_regions::connected_components(matching, |coor| {
+            self.neighborhood_cell_indexes(coor)
+                .filter(|neighbor| mask[self.index_to_vec_index(neighbor)])
+                .collect::<Vec<_>>()
+        });
+        Components::new(
+            components
+                .into_iter()
+                .enumerate()
+                .map(|(id, cells)| Component::new(id, cells))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 3x3x3 cube of rock with a hollow center
+    fn hollow_cube() -> GridArray3D<char> {
+        let mut grid = GridArray3DBuilder::default()
+            .width(3)
+            .height(3)
+            .depth(3)
+            .build()
+            .unwrap();
+        for coor in grid.all_indexes().collect::<Vec<_>>() {
+            grid.set(&coor, '#');
+        }
+        grid.set(&Coor3D::new(1, 1, 1), '.');
+        grid
+    }
+
+    #[test]
+    fn cells() {
+        let mut grid = hollow_cube();
+        assert_eq!(grid.get_depth(), 3);
+        assert_eq!(grid.all_cells().count(), 27);
+        assert_eq!(grid.get(&Coor3D::new(1, 1, 3)), None);
+        assert_eq!(grid.neighborhood_cells(&Coor3D::new(1, 1, 1)).count(), 6);
+        assert!(grid
+            .neighborhood_cells(&Coor3D::new(1, 1, 1))
+            .all(|(_, cell)| *cell == '#'));
+        assert_eq!(
+            grid.bounding_box(|(_, cell)| *cell == '.'),
+            Some((Coor3D::new(1, 1, 1), Coor3D::new(1, 1, 1)))
+        );
+
+        let data = grid.iter().copied().collect();
+        assert_eq!(
+            GridArray3D::from_1d_vec(Neighborhood3D::Face, 3, 3, data),
+            grid
+        );
+
+        *grid.get_mut(&Coor3D::new(1, 1, 2)).unwrap() = '.';
+        let components = grid.connected_components(|(_, cell)| *cell == '.');
+        assert_eq!(components.sizes().collect::<Vec<_>>(), [2]);
+        assert_eq!(components.components[0].max, Coor3D::new(1, 1, 2));
+    }
+
+    #[test]
+    fn neighborhoods() {
+        let grid = GridArray3DBuilder::<u8>::default()
+            .width(3)
+            .height(3)
+            .depth(3)
+            .neighborhood(Neighborhood3D::Vertex)
+            .build()
+            .unwrap();
+        assert_eq!(
+            grid.neighborhood_cell_indexes(&Coor3D::new(1, 1, 1))
+                .count(),
+            26
+        );
+        assert_eq!(
+            grid.neighborhood_cell_indexes(&Coor3D::new(0, 0, 0))
+                .count(),
+            7
+        );
+        let air = grid.flood_fill(&Coor3D::new(0, 0, 0), |_| true).unwrap();
+        assert_eq!(air.size(), 27);
+        assert_eq!(air.max, Coor3D::new(2, 2, 2));
+    }
+}
@@ -0,0 +1,468 @@
+//! Boolean grid with one bit per cell, the rows are stored as 64 bit words so that whole rows
+//! can be shifted, combined and counted at once (e.g. a cellular automaton step per row)
+
+use std::fmt::Display;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, Not};
+
+use crate::grid_iteration;
+use crate::grid_types::{Coor2D, Coor2DIndex, Coor2DMut, Direction, Neighborhood};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Row of bits, bit x is cell x (bits beyond the width are always cleared)
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BitRow {
+    width: usize,
+    words: Vec<u64>,
+}
+
+impl BitRow {
+    /// row with all bits cleared
+    pub fn new(width: usize) -> Self {
+        BitRow {
+            width,
+            words: vec![0; width.div_ceil(WORD_BITS)],
+        }
+    }
+
+    /// row with the bits of the given cells set
+    pub fn from_cells(width: usize, cells: impl IntoIterator<Item = bool>) -> Self {
+        let mut row = BitRow::new(width);
+        for (x, cell) in cells.into_iter().enumerate().take(width) {
+            row.set(x, cell);
+        }
+        row
+    }
+
+    /// `get_width`
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    /// words of the row (bit x is bit x % 64 of word x / 64)
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// bit x (None if x is outside of the row)
+    pub fn get(&self, x: usize) -> Option<bool> {
+        (x < self.width).then(|| self.words[x / WORD_BITS] & (1 << (x % WORD_BITS)) != 0)
+    }
+
+    /// set bit x and return old bit
+    pub fn set(&mut self, x: usize, value: bool) -> bool {
+        debug_assert!(x < self.width, "x={} width={}", x, self.width);
+        let old = self.get(x).unwrap_or_default();
+        let mask = 1 << (x % WORD_BITS);
+        if value {
+            self.words[x / WORD_BITS] |= mask;
+        } else {
+            self.words[x / WORD_BITS] &= !mask;
+        }
+        old
+    }
+
+    /// number of set bits
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// no bit is set
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    /// indexes of the set bits (ascending)
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(index, word)| {
+            let mut word = *word;
+            std::iter::from_fn(move || {
+                (word != 0).then(|| {
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    index * WORD_BITS + bit
+                })
+            })
+        })
+    }
+
+    /// Row with every bit moved from x to x + offset (bits moved out of the row are lost)
+    #[must_use]
+    pub fn shifted(&self, offset: isize) -> Self {
+        let count = self.words.len();
+        let distance = offset.unsigned_abs();
+        let (word_shift, bit_shift) = (distance / WORD_BITS, distance % WORD_BITS);
+        let mut words = vec![0; count];
+        for index in 0..count.saturating_sub(word_shift) {
+            // bits move to higher indexes for positive offsets
+            let (target, source, carry) = if offset >= 0 {
+                let source = index;
+                (source + word_shift, source, source.checked_sub(1))
+            } else {
+                let source = index + word_shift;
+                (
+                    index,
+                    source,
+                    Some(source + 1).filter(|carry| *carry < count),
+                )
+            };
+            let carried = carry
+                .filter(|_| bit_shift > 0)
+                .map_or(0, |carry| self.words[carry]);
+            words[target] = if offset >= 0 {
+                (self.words[source] << bit_shift)
+                    | (carried >> ((WORD_BITS - bit_shift) % WORD_BITS))
+            } else {
+                (self.words[source] >> bit_shift)
+                    | (carried << ((WORD_BITS - bit_shift) % WORD_BITS))
+            };
+        }
+        let mut row = BitRow {
+            width: self.width,
+            words,
+        };
+        row.clear_unused_bits();
+        row
+    }
+
+    /// Clears the bits beyond the width
+    fn clear_unused_bits(&mut self) {
+        let used_bits = self.width % WORD_BITS;
+        if used_bits > 0 {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1 << used_bits) - 1;
+            }
+        }
+    }
+
+    fn combine(&self, other: &Self, op: impl Fn(u64, u64) -> u64) -> Self {
+        debug_assert_eq!(self.width, other.width);
+        BitRow {
+            width: self.width,
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(left, right)| op(*left, *right))
+                .collect(),
+        }
+    }
+
+    /// bits set in self but not in other
+    #[must_use]
+    pub fn and_not(&self, other: &Self) -> Self {
+        self.combine(other, |left, right| left & !right)
+    }
+}
+
+impl BitAnd for &BitRow {
+    type Output = BitRow;
+
+    fn bitand(self, other: Self) -> BitRow {
+        self.combine(other, |left, right| left & right)
+    }
+}
+
+impl BitOr for &BitRow {
+    type Output = BitRow;
+
+    fn bitor(self, other: Self) -> BitRow {
+        self.combine(other, |left, right| left | right)
+    }
+}
+
+impl BitXor for &BitRow {
+    type Output = BitRow;
+
+    fn bitxor(self, other: Self) -> BitRow {
+        self.combine(other, |left, right| left ^ right)
+    }
+}
+
+impl BitAndAssign<&BitRow> for BitRow {
+    fn bitand_assign(&mut self, other: &BitRow) {
+        *self = &*self & other;
+    }
+}
+
+impl BitOrAssign<&BitRow> for BitRow {
+    fn bitor_assign(&mut self, other: &BitRow) {
+        *self = &*self | other;
+    }
+}
+
+impl Not for &BitRow {
+    type Output = BitRow;
+
+    fn not(self) -> BitRow {
+        let mut row = BitRow {
+            width: self.width,
+            words: self.words.iter().map(|word| !word).collect(),
+        };
+        row.clear_unused_bits();
+        row
+    }
+}
+
+impl Display for BitRow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for x in 0..self.width {
+            write!(f, "{}", if self.get(x) == Some(true) { '#' } else { '.' })?;
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+/// `GridBits`, a bounded grid of bools (no wrap-around)
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GridBits {
+    width: Coor2DIndex,
+    height: Coor2DIndex,
+    rows: Vec<BitRow>,
+}
+
+impl Display for GridBits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in &self.rows {
+            writeln!(f, "{row}")?;
+        }
+        Ok(())
+    }
+}
+
+impl GridBits {
+    /// grid with all cells cleared
+    pub fn new(width: Coor2DIndex, height: Coor2DIndex) -> Self {
+        GridBits {
+            width,
+            height,
+            rows: vec![BitRow::new(width); height],
+        }
+    }
+
+    /// from newline separated string, the cells with `set_char` are set
+    /// #Panics panics if the string is not a rectangle
+    pub fn from_newline_separated_string(input: &str, set_char: char) -> Self {
+        let lines = input.lines().collect::<Vec<_>>();
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        GridBits {
+            width,
+            height: lines.len(),
+            rows: lines
+                .into_iter()
+                .map(|line| {
+                    assert_eq!(line.chars().count(), width, "line '{line}'");
+                    BitRow::from_cells(width, line.chars().map(|ch| ch == set_char))
+                })
+                .collect(),
+        }
+    }
+
+    /// `get_width`
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    /// `get_height`
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    /// get cell on x, y
+    pub fn get(&self, x: Coor2DIndex, y: Coor2DIndex) -> Option<bool> {
+        self.rows.get(y)?.get(x)
+    }
+
+    /// set cell on x, y and return old cell
+    pub fn set(&mut self, x: Coor2DIndex, y: Coor2DIndex, value: bool) -> bool {
+        debug_assert!(y < self.height, "y={} height={}", y, self.height);
+        self.rows[y].set(x, value)
+    }
+
+    /// row y
+    pub fn row(&self, y: Coor2DIndex) -> Option<&BitRow> {
+        self.rows.get(y)
+    }
+
+    /// all rows (from top to bottom)
+    pub fn rows(&self) -> &[BitRow] {
+        &self.rows
+    }
+
+    /// replace row y and return the old row
+    pub fn set_row(&mut self, y: Coor2DIndex, row: BitRow) -> BitRow {
+        debug_assert_eq!(row.width, self.width);
+        std::mem::replace(&mut self.rows[y], row)
+    }
+
+    /// number of set cells
+    pub fn count_ones(&self) -> usize {
+        self.rows.iter().map(BitRow::count_ones).sum()
+    }
+
+    /// all set cells (in reading order)
+    pub fn all_set_cells(&self) -> impl Iterator<Item = Coor2D> + '_ {
+        self.rows
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter_ones().map(move |x| Coor2D::new(x, y)))
+    }
+
+    /// Row of the neighbors in direction of the cells in row y: bit x is the cell next to x, y
+    /// in direction (cleared if that is outside of the grid)
+    pub fn neighbor_row(&self, y: Coor2DIndex, direction: Direction) -> BitRow {
+        self.moved_row(y, &direction.diff_coor())
+    }
+
+    /// Row of the cells moved by `diff` of the cells in row y: bit x is the cell x + diff.x,
+    /// y + diff.y (cleared if that is outside of the grid)
+    pub fn moved_row(&self, y: Coor2DIndex, diff: &Coor2DMut<isize>) -> BitRow {
+        y.checked_add_signed(diff.y)
+            .and_then(|neighbor_y| self.rows.get(neighbor_y))
+            .map_or_else(|| BitRow::new(self.width), |row| row.shifted(-diff.x))
+    }
+
+    /// Row of the cells in row y with at least one set neighbor in the given directions
+    pub fn any_neighbor_in(
+        &self,
+        y: Coor2DIndex,
+        directions: impl IntoIterator<Item = Direction>,
+    ) -> BitRow {
+        directions
+            .into_iter()
+            .fold(BitRow::new(self.width), |mut any, direction| {
+                any |= &self.neighbor_row(y, direction);
+                any
+            })
+    }
+
+    /// Row of the cells in row y with at least one set neighbor (based on neighborhood)
+    pub fn any_neighbor(&self, y: Coor2DIndex, neighborhood: Neighborhood) -> BitRow {
+        grid_iteration::all_adjacent_cells(neighborhood, isize::try_from(y).unwrap()).fold(
+            BitRow::new(self.width),
+            |mut any, diff| {
+                any |= &self.moved_row(y, &diff);
+                any
+            },
+        )
+    }
+
+    /// number of set neighbors of x, y (based on neighborhood)
+    pub fn neighbor_count(
+        &self,
+        x: Coor2DIndex,
+        y: Coor2DIndex,
+        neighborhood: Neighborhood,
+    ) -> usize {
+        grid_iteration::all_adjacent_cells(neighborhood, isize::try_from(y).unwrap())
+            .filter(|diff| {
+                x.checked_add_signed(diff.x)
+                    .zip(y.checked_add_signed(diff.y))
+                    .and_then(|(x, y)| self.get(x, y))
+                    .unwrap_or_default()
+            })
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_types::HexLayout;
+
+    fn row(cells: &str) -> BitRow {
+        BitRow::from_cells(cells.len(), cells.chars().map(|ch| ch == '#'))
+    }
+
+    #[test]
+    fn rows() {
+        let wide = (0..150).map(|x| x % 3 == 0).collect::<Vec<_>>();
+        let wide = BitRow::from_cells(150, wide);
+        assert_eq!(wide.count_ones(), 50);
+        assert_eq!(wide.iter_ones().take(3).collect::<Vec<_>>(), vec![0, 3, 6]);
+        for offset in [-130, -64, -65, -1, 0, 1, 63, 64, 70, 149, 150] {
+            let shifted = wide.shifted(offset);
+            for x in 0..150 {
+                let source = x - offset;
+                let expected = (0..150).contains(&source) && source % 3 == 0;
+                assert_eq!(
+                    shifted.get(x.unsigned_abs()),
+                    Some(expected),
+                    "offset={offset}"
+                );
+            }
+        }
+
+        let (a, b) = (row("##..#"), row("#.#.#"));
+        assert_eq!((&a & &b).to_string(), "#...#");
+        assert_eq!((&a | &b).to_string(), "###.#");
+        assert_eq!((&a ^ &b).to_string(), ".##..");
+        assert_eq!((!&a).to_string(), "..##.");
+        assert_eq!((!&a).count_ones(), 2);
+        assert_eq!(a.and_not(&b).to_string(), ".#...");
+        assert!(!a.is_empty() && a.shifted(5).is_empty());
+    }
+
+    #[test]
+    fn grid() {
+        let mut grid = GridBits::from_newline_separated_string("#...\n.#..\n...#\n", '#');
+        assert_eq!((grid.get_width(), grid.get_height()), (4, 3));
+        assert_eq!(grid.count_ones(), 3);
+        assert_eq!(grid.get(1, 1), Some(true));
+        assert_eq!(grid.get(4, 1), None);
+        assert!(!grid.set(2, 0, true));
+        assert_eq!(grid.to_string(), "#.#.\n.#..\n...#\n");
+        assert_eq!(
+            grid.all_set_cells().collect::<Vec<_>>(),
+            vec![
+                Coor2D::new(0, 0),
+                Coor2D::new(2, 0),
+                Coor2D::new(1, 1),
+                Coor2D::new(3, 2)
+            ]
+        );
+
+        // bit x is set if the cell north east of x, 1 is set
+        assert_eq!(
+            grid.neighbor_row(1, Direction::NorthEast).to_string(),
+            ".#.."
+        );
+        assert_eq!(grid.neighbor_row(0, Direction::North).to_string(), "....");
+        assert_eq!(
+            grid.neighbor_row(1, Direction::SouthWest).to_string(),
+            "...."
+        );
+        assert_eq!(
+            grid.neighbor_row(1, Direction::SouthEast).to_string(),
+            "..#."
+        );
+        assert_eq!(
+            grid.any_neighbor(1, Neighborhood::Square).to_string(),
+            "####"
+        );
+        assert_eq!(
+            grid.any_neighbor(1, Neighborhood::Orthogonal).to_string(),
+            "#.##"
+        );
+        for neighborhood in [
+            Neighborhood::Square,
+            Neighborhood::Hex(HexLayout::OddRows),
+            Neighborhood::Knight,
+        ] {
+            for y in 0..grid.get_height() {
+                let any = grid.any_neighbor(y, neighborhood);
+                for x in 0..grid.get_width() {
+                    let count = grid.neighbor_count(x, y, neighborhood);
+                    assert_eq!(any.get(x), Some(count > 0));
+                }
+            }
+        }
+    }
+}
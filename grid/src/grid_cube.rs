@@ -0,0 +1,413 @@
+//! Folding a flat net of six square faces onto a cube
+//! ([`Topology::CubeNet`](crate::grid_types::Topology::CubeNet)), stepping over an edge of a face
+//! leads to the face on the other side of the cube edge (with a new direction)
+//!
+//! The face size and the folding are detected from the cells of the net (any of the 11 nets)
+
+use std::collections::VecDeque;
+
+use crate::grid_types::{Coor2D, Coor2DIndex, Coor2DMut, Direction};
+
+/// Axis aligned unit vector in 3D (the frames of the faces, small to keep `Topology` small)
+type Vector = [i8; 3];
+/// Point in 3D
+type Point = [isize; 3];
+
+fn neg(vector: Vector) -> Vector {
+    vector.map(|value| -value)
+}
+
+fn dot(vector: Vector, point: Point) -> isize {
+    vector
+        .iter()
+        .zip(point)
+        .map(|(value, point)| isize::from(*value) * point)
+        .sum()
+}
+
+/// One face of the net: the tile it covers and how it lies on the cube
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct CubeFace {
+    /// x, y of the tile (in units of the face size)
+    tile: (u16, u16),
+    /// outward normal
+    normal: Vector,
+    /// direction of increasing x on the cube
+    right: Vector,
+    /// direction of increasing y on the cube
+    down: Vector,
+}
+
+impl CubeFace {
+    /// 3D direction of a 2D direction on this face
+    fn vector(&self, direction: Direction) -> Vector {
+        match direction {
+            Direction::East => self.right,
+            Direction::South => self.down,
+            Direction::West => neg(self.right),
+            Direction::North => neg(self.down),
+            _ => panic!("{direction:?} is not orthogonal"),
+        }
+    }
+
+    /// coor of the upper left cell
+    fn origin(&self, face_size: Coor2DIndex) -> (Coor2DIndex, Coor2DIndex) {
+        (
+            usize::from(self.tile.0) * face_size,
+            usize::from(self.tile.1) * face_size,
+        )
+    }
+
+    /// the face (on `tile`) folded over the edge in direction (turned by 90° around the edge)
+    fn folded(&self, tile: (u16, u16), direction: Direction) -> CubeFace {
+        let mut folded = CubeFace {
+            tile,
+            normal: self.vector(direction),
+            ..*self
+        };
+        match direction {
+            Direction::East => folded.right = neg(self.normal),
+            Direction::South => folded.down = neg(self.normal),
+            Direction::West => folded.right = self.normal,
+            Direction::North => folded.down = self.normal,
+            _ => panic!("{direction:?} is not orthogonal"),
+        }
+        folded
+    }
+}
+
+/// Folding of a net of six faces (see [`CubeNet::detect`])
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CubeNet {
+    face_size: Coor2DIndex,
+    /// faces in reading order of their tiles
+    faces: [CubeFace; 6],
+}
+
+impl CubeNet {
+    /// Detects face size and folding of the net formed by the cells of a `width` x `height` grid
+    /// for which `is_face` is true, None if they are not a net of a cube
+    pub fn detect(
+        width: Coor2DIndex,
+        height: Coor2DIndex,
+        is_face: impl Fn(Coor2DIndex, Coor2DIndex) -> bool,
+    ) -> Option<CubeNet> {
+        let cell_count = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .filter(|(x, y)| is_face(*x, *y))
+            .count();
+        let face_size = (1..=width.max(height)).find(|size| 6 * size * size >= cell_count)?;
+        if 6 * face_size * face_size != cell_count {
+            return None;
+        }
+
+        // every tile is a face or completely empty
+        let tiles = (0..u16::try_from(height / face_size).ok()?)
+            .flat_map(|y| (0..u16::try_from(width / face_size).unwrap()).map(move |x| (x, y)))
+            .filter(|(x, y)| is_face(usize::from(*x) * face_size, usize::from(*y) * face_size))
+            .collect::<Vec<_>>();
+        let is_full = |(tile_x, tile_y): (u16, u16)| {
+            let (x0, y0) = (
+                usize::from(tile_x) * face_size,
+                usize::from(tile_y) * face_size,
+            );
+            (0..face_size).all(|y| (0..face_size).all(|x| is_face(x0 + x, y0 + y)))
+        };
+        if tiles.len() != 6 || !tiles.iter().copied().all(is_full) {
+            return None;
+        }
+
+        // fold the faces around the first one
+        let mut faces: Vec<Option<CubeFace>> = vec![None; 6];
+        faces[0] = Some(CubeFace {
+            tile: tiles[0],
+            normal: [0, 0, 1],
+            right: [1, 0, 0],
+            down: [0, 1, 0],
+        });
+        let mut queue = VecDeque::from([0]);
+        while let Some(index) = queue.pop_front() {
+            let face = faces[index].unwrap();
+            for direction in [
+                Direction::North,
+                Direction::East,
+                Direction::South,
+                Direction::West,
+            ] {
+                let diff = direction.diff_coor();
+                let neighbor_tile = face
+                    .tile
+                    .0
+                    .checked_add_signed(diff.x.try_into().unwrap())
+                    .zip(face.tile.1.checked_add_signed(diff.y.try_into().unwrap()));
+                let Some(neighbor) = tiles.iter().position(|tile| Some(*tile) == neighbor_tile)
+                else {
+                    continue;
+                };
+                if faces[neighbor].is_none() {
+                    faces[neighbor] = Some(face.folded(tiles[neighbor], direction));
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        let faces = faces.into_iter().collect::<Option<Vec<_>>>()?;
+        // a net covers every side of the cube once
+        let all_sides = faces.iter().all(|face| {
+            faces
+                .iter()
+                .filter(|other| other.normal == face.normal)
+                .count()
+                == 1
+        });
+        all_sides.then(|| CubeNet {
+            face_size,
+            faces: faces.try_into().unwrap(),
+        })
+    }
+
+    /// `get_face_size`
+    pub fn get_face_size(&self) -> Coor2DIndex {
+        self.face_size
+    }
+
+    /// Index (0..6, reading order of the faces in the net) of the face containing coor
+    pub fn face_index(&self, coor: &Coor2D) -> Option<usize> {
+        let tile = (coor.x / self.face_size, coor.y / self.face_size);
+        self.faces
+            .iter()
+            .position(|face| (usize::from(face.tile.0), usize::from(face.tile.1)) == tile)
+    }
+
+    /// Is coor on a face of the net
+    pub fn contains(&self, coor: &Coor2D) -> bool {
+        self.face_index(coor).is_some()
+    }
+
+    /// Returns the adjacent cell in direction and the direction after the step (it changes when
+    /// stepping over an edge of the cube), diagonal steps go orthogonal first and turn then
+    pub fn adjacent_cell(
+        &self,
+        coor: &Coor2D,
+        direction: Direction,
+    ) -> Option<(Coor2D, Direction)> {
+        if direction as u8 % 2 == 1 {
+            // e.g. NorthEast: North, then East relative to the direction after the first step
+            let (coor, first) = self.adjacent_cell(coor, direction.rotate(-45))?;
+            let (coor, second) = self.adjacent_cell(&coor, first.rotate(90))?;
+            return Some((coor, second.rotate(-45)));
+        }
+
+        let face = self.faces[self.face_index(coor)?];
+        let size = self.face_size;
+        let (x0, y0) = face.origin(size);
+        let diff = direction.diff_coor();
+        let inside = |value: Coor2DIndex, diff: isize| {
+            value.checked_add_signed(diff).filter(|value| *value < size)
+        };
+        if let (Some(x), Some(y)) = (inside(coor.x - x0, diff.x), inside(coor.y - y0, diff.y)) {
+            return Some((Coor2D::new(x0 + x, y0 + y), direction));
+        }
+
+        // cells are points on the surface of a cube of side 2 * size around 0, 0, 0
+        let size_isize = isize::try_from(size).unwrap();
+        let on_axis = |value: Coor2DIndex| 2 * isize::try_from(value).unwrap() + 1 - size_isize;
+        let point = |face: &CubeFace, x: Coor2DIndex, y: Coor2DIndex| -> Point {
+            std::array::from_fn(|axis| {
+                isize::from(face.normal[axis]) * size_isize
+                    + isize::from(face.right[axis]) * on_axis(x)
+                    + isize::from(face.down[axis]) * on_axis(y)
+            })
+        };
+        let vector = face.vector(direction);
+        let current = point(&face, coor.x - x0, coor.y - y0);
+        let next: Point = std::array::from_fn(|axis| {
+            current[axis] + isize::from(vector[axis]) - isize::from(face.normal[axis])
+        });
+
+        let next_face = self.faces.iter().find(|other| other.normal == vector)?;
+        let to_index = |axis: Vector| ((dot(axis, next) + size_isize - 1) / 2).unsigned_abs();
+        let (next_left, next_top) = next_face.origin(size);
+        let next_coor = Coor2D::new(
+            next_left + to_index(next_face.right),
+            next_top + to_index(next_face.down),
+        );
+        let next_direction = [
+            Direction::North,
+            Direction::East,
+            Direction::South,
+            Direction::West,
+        ]
+        .into_iter()
+        .find(|direction| next_face.vector(*direction) == neg(face.normal))?;
+        Some((next_coor, next_direction))
+    }
+
+    /// Returns the cell moved by `diff` (first along y, then turning to x like a walker on the
+    /// cube)
+    pub fn moved_cell(&self, coor: &Coor2D, diff: &Coor2DMut<isize>) -> Option<Coor2D> {
+        let first = if diff.y > 0 {
+            Direction::South
+        } else {
+            Direction::North
+        };
+        let second = if diff.x < 0 {
+            Direction::West
+        } else {
+            Direction::East
+        };
+        let (mut coor, mut direction) = (coor.clone(), first);
+        for _ in 0..diff.y.unsigned_abs() {
+            (coor, direction) = self.adjacent_cell(&coor, direction)?;
+        }
+        direction = direction.rotate((second as isize - first as isize) * 45);
+        for _ in 0..diff.x.unsigned_abs() {
+            (coor, direction) = self.adjacent_cell(&coor, direction)?;
+        }
+        Some(coor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_array::GridArray;
+    use crate::grid_types::{Neighborhood, Topology};
+
+    /// the net of the tiles ('#' is a face) with faces of `size` x `size` cells
+    fn net(tiles: &str, size: usize) -> Option<GridArray<char>> {
+        let input = tiles
+            .lines()
+            .flat_map(|line| {
+                let line = line
+                    .chars()
+                    .flat_map(|tile| std::iter::repeat_n(tile, size))
+                    .collect::<String>();
+                std::iter::repeat_n(line + "\n", size)
+            })
+            .collect::<String>();
+        GridArray::from_newline_separated_string(
+            Topology::Bounded,
+            Neighborhood::Orthogonal,
+            &input,
+        )
+        .with_cube_net(|tile| *tile == '#')
+    }
+
+    const ORTHOGONAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    #[test]
+    fn example_of_day_22() {
+        let grid = net("..#.\n###.\n..##\n", 4).unwrap();
+        let Topology::CubeNet(cube) = grid.get_topology() else {
+            panic!("no cube net");
+        };
+        assert_eq!(cube.get_face_size(), 4);
+        assert_eq!(cube.face_index(&Coor2D::new(9, 1)), Some(0));
+        assert_eq!(cube.face_index(&Coor2D::new(1, 1)), None);
+
+        let step = |x, y, direction| grid.adjacent_cell_with_direction(x, y, direction);
+        assert_eq!(
+            step(5, 5, Direction::East),
+            Some((Coor2D::new(6, 5), Direction::East))
+        );
+        assert_eq!(
+            step(11, 5, Direction::East),
+            Some((Coor2D::new(14, 8), Direction::South))
+        );
+        assert_eq!(
+            step(10, 11, Direction::South),
+            Some((Coor2D::new(1, 7), Direction::North))
+        );
+        assert_eq!(
+            step(6, 4, Direction::North),
+            Some((Coor2D::new(8, 2), Direction::East))
+        );
+        assert_eq!(
+            grid.adjacent_cell(6, 4, Direction::North),
+            Some(Coor2D::new(8, 2))
+        );
+        assert_eq!(grid.neighborhood_cell_indexes(8, 0).count(), 4);
+    }
+
+    #[test]
+    fn all_nets() {
+        let nets = [
+            "#...\n####\n#...\n",
+            "#...\n####\n.#..\n",
+            "#...\n####\n..#.\n",
+            "#...\n####\n...#\n",
+            ".#..\n####\n.#..\n",
+            ".#..\n####\n..#.\n",
+            "##..\n.###\n.#..\n",
+            "##..\n.###\n..#.\n",
+            "##..\n.###\n...#\n",
+            "##..\n.##.\n..##\n",
+            "###..\n..###\n",
+        ];
+        for tiles in nets {
+            let size = 3;
+            let grid = net(tiles, size).unwrap_or_else(|| panic!("no net:\n{tiles}"));
+            let Topology::CubeNet(cube) = grid.get_topology() else {
+                panic!("no cube net");
+            };
+            for coor in grid.all_indexes().filter(|coor| cube.contains(coor)) {
+                for direction in ORTHOGONAL {
+                    // stepping back leads to the start
+                    let (next, next_direction) = cube.adjacent_cell(&coor, direction).unwrap();
+                    assert_eq!(
+                        cube.adjacent_cell(&next, next_direction.rotate(180)),
+                        Some((coor.clone(), direction.rotate(180))),
+                        "{coor} {direction:?}\n{tiles}"
+                    );
+
+                    // going around the cube
+                    let mut walker = (coor.clone(), direction);
+                    for _ in 0..4 * size {
+                        walker = cube.adjacent_cell(&walker.0, walker.1).unwrap();
+                    }
+                    assert_eq!(walker, (coor.clone(), direction));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn no_nets() {
+        assert!(net("###\n###\n", 2).is_none());
+        assert!(net("####\n.##.\n", 2).is_none());
+        assert!(net("#...\n####\n", 2).is_none());
+        assert!(CubeNet::detect(3, 3, |x, y| x + y < 3).is_none());
+    }
+
+    #[test]
+    fn moved_cells() {
+        let grid = net("..#.\n###.\n..##\n", 2).unwrap();
+        let Topology::CubeNet(cube) = grid.get_topology() else {
+            panic!("no cube net");
+        };
+        let start = Coor2D::new(4, 0);
+        assert_eq!(
+            cube.moved_cell(&start, &Coor2DMut::new(1, 1)),
+            Some(Coor2D::new(5, 1))
+        );
+        assert_eq!(
+            cube.moved_cell(&start, &Coor2DMut::new(0, -8)),
+            Some(start.clone())
+        );
+        // North over the edge to the face left of the middle row, then "East" on it
+        let (north, direction) = cube.adjacent_cell(&start, Direction::North).unwrap();
+        assert_eq!(
+            cube.moved_cell(&start, &Coor2DMut::new(1, -1)),
+            cube.adjacent_cell(&north, direction.rotate(90))
+                .map(|(coor, _)| coor)
+        );
+    }
+}
@@ -0,0 +1,325 @@
+//! Differences between grids ([`GridDiff`]) and a journal of the changes of a grid grouped into
+//! steps ([`ChangeJournal`]), e.g. to print only the cells a simulation step changed, to replay
+//! or to undo steps
+//!
+//! An empty cell is None: a missing cell of a hash map grid or a default cell of an array grid
+
+use std::fmt::Display;
+
+/// Change of the cell on coor
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CellChange<C, T> {
+    /// coor of the cell
+    pub coor: C,
+    /// cell before the change (None: empty)
+    pub old: Option<T>,
+    /// cell after the change (None: empty)
+    pub new: Option<T>,
+}
+
+impl<C: Clone, T: Clone> CellChange<C, T> {
+    /// The change undoing this change
+    #[must_use]
+    pub fn reversed(&self) -> Self {
+        CellChange {
+            coor: self.coor.clone(),
+            old: self.new.clone(),
+            new: self.old.clone(),
+        }
+    }
+}
+
+/// Changed cells (in the order they were found or recorded)
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GridDiff<C, T> {
+    changes: Vec<CellChange<C, T>>,
+}
+
+impl<C, T> Default for GridDiff<C, T> {
+    fn default() -> Self {
+        GridDiff {
+            changes: Vec::new(),
+        }
+    }
+}
+
+impl<C: Clone, T: Clone> GridDiff<C, T> {
+    /// Diff without changes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// add a change
+    pub fn push(&mut self, change: CellChange<C, T>) {
+        self.changes.push(change);
+    }
+
+    /// all changes
+    pub fn changes(&self) -> &[CellChange<C, T>] {
+        &self.changes
+    }
+
+    /// number of changes
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// no changes
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// cells that were empty before
+    pub fn added(&self) -> impl Iterator<Item = (&C, &T)> {
+        self.changes.iter().filter_map(|change| match change {
+            CellChange {
+                coor,
+                old: None,
+                new: Some(new),
+            } => Some((coor, new)),
+            _ => None,
+        })
+    }
+
+    /// cells that are empty now
+    pub fn removed(&self) -> impl Iterator<Item = (&C, &T)> {
+        self.changes.iter().filter_map(|change| match change {
+            CellChange {
+                coor,
+                old: Some(old),
+                new: None,
+            } => Some((coor, old)),
+            _ => None,
+        })
+    }
+
+    /// cells with a new value as (coor, old, new)
+    pub fn changed(&self) -> impl Iterator<Item = (&C, &T, &T)> {
+        self.changes.iter().filter_map(|change| match change {
+            CellChange {
+                coor,
+                old: Some(old),
+                new: Some(new),
+            } => Some((coor, old, new)),
+            _ => None,
+        })
+    }
+
+    /// The diff undoing this diff (reversed changes in reversed order)
+    #[must_use]
+    pub fn reversed(&self) -> Self {
+        GridDiff {
+            changes: self
+                .changes
+                .iter()
+                .rev()
+                .map(CellChange::reversed)
+                .collect(),
+        }
+    }
+}
+
+/// One change per line: `+coor: new`, `-coor: old` or `coor: old -> new`
+impl<C: Display, T: Display> Display for GridDiff<C, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for change in &self.changes {
+            match (&change.old, &change.new) {
+                (None, Some(new)) => writeln!(f, "+{}: {new}", change.coor)?,
+                (Some(old), None) => writeln!(f, "-{}: {old}", change.coor)?,
+                (Some(old), Some(new)) => writeln!(f, "{}: {old} -> {new}", change.coor)?,
+                (None, None) => writeln!(f, "{}: empty", change.coor)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Changes of a grid grouped into steps (see e.g. `GridArray::start_journal`)
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChangeJournal<C, T> {
+    steps: Vec<GridDiff<C, T>>,
+    current: GridDiff<C, T>,
+}
+
+impl<C, T> Default for ChangeJournal<C, T> {
+    fn default() -> Self {
+        ChangeJournal {
+            steps: Vec::new(),
+            current: GridDiff::default(),
+        }
+    }
+}
+
+impl<C: Clone, T: Clone> ChangeJournal<C, T> {
+    /// Journal without changes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// record a change in the current step
+    pub fn record(&mut self, change: CellChange<C, T>) {
+        self.current.push(change);
+    }
+
+    /// Ends the current step (also if it has no changes, the step indexes stay aligned)
+    pub fn end_step(&mut self) {
+        self.steps.push(std::mem::take(&mut self.current));
+    }
+
+    /// all ended steps (apply them in order to the start grid to replay)
+    pub fn steps(&self) -> &[GridDiff<C, T>] {
+        &self.steps
+    }
+
+    /// changes of the step not ended yet
+    pub fn current(&self) -> &GridDiff<C, T> {
+        &self.current
+    }
+
+    /// Removes and returns the current step if it has changes, otherwise the last ended step
+    pub fn pop_step(&mut self) -> Option<GridDiff<C, T>> {
+        if self.current.is_empty() {
+            self.steps.pop()
+        } else {
+            Some(std::mem::take(&mut self.current))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_array::GridArray;
+    use crate::grid_hashmap::GridHashMap;
+    use crate::grid_types::{Coor2D, Coor2DMut, Neighborhood, Topology};
+
+    /// array grid of the input, '.' is an empty cell
+    fn array(input: &str) -> GridArray<char> {
+        let rows = input
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|ch| if ch == '.' { '\0' } else { ch })
+                    .collect()
+            })
+            .collect();
+        GridArray::from_2d_vec(Topology::Bounded, Neighborhood::Square, rows)
+    }
+
+    #[test]
+    fn diffs() {
+        let before = array("#.o\n...\n");
+        let after = array("..#\n.o.\n");
+        let diff = before.diff(&after);
+        assert_eq!(
+            diff.added().collect::<Vec<_>>(),
+            vec![(&Coor2D::new(1, 1), &'o')]
+        );
+        assert_eq!(
+            diff.removed().collect::<Vec<_>>(),
+            vec![(&Coor2D::new(0, 0), &'#')]
+        );
+        assert_eq!(
+            diff.changed().collect::<Vec<_>>(),
+            vec![(&Coor2D::new(2, 0), &'o', &'#')]
+        );
+        assert_eq!(diff.to_string(), "-(0,0): #\n(2,0): o -> #\n+(1,1): o\n");
+        assert!(before.diff(&before).is_empty());
+
+        let mut patched = before.clone();
+        patched.apply_diff(&diff);
+        assert_eq!(patched, after);
+        patched.apply_diff(&diff.reversed());
+        assert_eq!(patched, before);
+
+        let before = GridHashMap::from_newline_separated_string(Neighborhood::Square, "ab\ncd\n");
+        let mut after = before.clone();
+        after.remove(&Coor2DMut::new(1, 0));
+        after.set(Coor2DMut::new(0, 1), 'x');
+        after.set(Coor2DMut::new(-1, 0), 'y');
+        let diff = before.diff(&after);
+        assert_eq!(diff.to_string(), "+(-1,0): y\n-(1,0): b\n(0,1): c -> x\n");
+        let mut patched = before.clone();
+        patched.apply_diff(&diff);
+        assert_eq!(patched, after);
+    }
+
+    #[test]
+    fn journals() {
+        let start = array("...\n...\n");
+        let mut grid = start.clone();
+        grid.start_journal();
+        grid.set(0, 0, 'o');
+        grid.end_step();
+        grid.set(0, 0, '\0');
+        grid.set(0, 1, 'o');
+        grid.end_step();
+        grid.end_step();
+        grid.set(2, 1, '#');
+
+        let journal = grid.get_journal().unwrap();
+        assert_eq!(
+            journal
+                .steps()
+                .iter()
+                .map(GridDiff::len)
+                .collect::<Vec<_>>(),
+            vec![1, 2, 0]
+        );
+        assert_eq!(journal.current().len(), 1);
+        // empty cells are None like in a diff
+        let second = &journal.steps()[1];
+        let after_first = array("o..\n...\n");
+        let after_second = array("...\no..\n");
+        assert_eq!(
+            second.removed().collect::<Vec<_>>(),
+            after_first
+                .diff(&after_second)
+                .removed()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            second.added().collect::<Vec<_>>(),
+            after_first.diff(&after_second).added().collect::<Vec<_>>()
+        );
+        assert_eq!(second.to_string(), "-(0,0): o\n+(0,1): o\n");
+
+        // replay
+        let mut replayed = start.clone();
+        for step in journal.steps() {
+            replayed.apply_diff(step);
+        }
+        assert_eq!(replayed.get(0, 1), Some(&'o'));
+        assert_eq!(replayed.get(2, 1), Some(&'\0'));
+
+        // undo
+        let current = grid.undo_step().unwrap();
+        assert_eq!(current.len(), 1);
+        assert_eq!(grid.get(2, 1), Some(&'\0'));
+        assert!(grid.undo_step().unwrap().is_empty());
+        grid.undo_step();
+        assert_eq!(grid.get(0, 0), Some(&'o'));
+        assert_eq!(grid.get(0, 1), Some(&'\0'));
+        assert_eq!(grid.get_journal().unwrap().steps().len(), 1);
+        grid.undo_step();
+        assert_eq!(grid.undo_step(), None);
+        // the journal is ignored by eq and not cloned
+        assert_eq!(grid, start);
+        assert_eq!(grid.clone().get_journal(), None);
+        assert_eq!(grid.take_journal(), Some(ChangeJournal::new()));
+
+        let mut hashmap = GridHashMap::from_newline_separated_string(Neighborhood::Square, "ab\n");
+        hashmap.start_journal();
+        hashmap.remove(&Coor2DMut::new(0, 0));
+        hashmap.set(Coor2DMut::new(5, 5), 'c');
+        hashmap.end_step();
+        let step = &hashmap.get_journal().unwrap().steps()[0];
+        assert_eq!(step.to_string(), "-(0,0): a\n+(5,5): c\n");
+        hashmap.undo_step();
+        assert_eq!(hashmap.get(&Coor2DMut::new(0, 0)), Some(&'a'));
+        assert_eq!(hashmap.get(&Coor2DMut::new(5, 5)), None);
+    }
+}
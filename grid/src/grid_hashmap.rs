@@ -1,17 +1,23 @@
 //! Grid based on a hash map
 
+use std::fmt::Display;
+use std::str::FromStr;
+
 use fxhash::FxHashMap;
 
-use crate::grid_types::Coor2DMut;
+use crate::grid_diff::{CellChange, ChangeJournal, GridDiff};
+use crate::grid_text::{self, ParseGridError};
+use crate::grid_types::{Coor2DMut, Direction};
 
 use super::grid_iteration;
-use super::grid_types::Neighborhood;
+use super::grid_types::{Neighborhood, Topology};
 
 use itertools::Itertools;
 
 /// GridArray
 #[allow(missing_docs)]
-#[derive(Builder, Clone, PartialEq, Debug)]
+#[derive(Builder, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GridHashMap<T>
 where
     T: Default + Clone + std::fmt::Display,
@@ -19,8 +25,61 @@ where
     #[builder(default = "Neighborhood::Square")]
     neighborhood: Neighborhood,
 
+    /// only used if there are bounds
+    #[builder(default = "Topology::Bounded")]
+    topology: Topology,
+    /// min and max coor (both included), None for an unbounded grid
+    #[builder(default = "None")]
+    bounds: Option<(Coor2DMut<isize>, Coor2DMut<isize>)>,
+
     #[builder(default = "FxHashMap::default()")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            with = "grid_text::serde_cells",
+            bound(
+                serialize = "T: serde::Serialize",
+                deserialize = "T: serde::Deserialize<'de>"
+            )
+        )
+    )]
     data: FxHashMap<Coor2DMut<isize>, T>,
+
+    /// records the cells changed by `set` and `remove` (see `start_journal`), ignored by `eq`
+    /// and not cloned
+    #[builder(setter(skip))]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    journal: Option<ChangeJournal<Coor2DMut<isize>, T>>,
+}
+
+/// A clone has no journal (clones in a simulation would copy the whole history)
+impl<T> Clone for GridHashMap<T>
+where
+    T: Default + Clone + std::fmt::Display,
+{
+    fn clone(&self) -> Self {
+        GridHashMap {
+            neighborhood: self.neighborhood,
+            topology: self.topology,
+            bounds: self.bounds.clone(),
+            data: self.data.clone(),
+            journal: None,
+        }
+    }
+}
+
+/// Grids are equal if they have the same neighborhood, topology, bounds and cells (the journal
+/// is ignored)
+impl<T> PartialEq for GridHashMap<T>
+where
+    T: Default + Clone + std::fmt::Display + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.neighborhood == other.neighborhood
+            && self.topology == other.topology
+            && self.bounds == other.bounds
+            && self.data == other.data
+    }
 }
 
 impl GridHashMap<char> {
@@ -43,10 +102,83 @@ impl GridHashMap<char> {
     }
 }
 
+/// Parses every char as a cell with `T::from_str`, an unbounded grid with the square neighborhood
+impl<T> FromStr for GridHashMap<T>
+where
+    T: Default + Clone + Display + FromStr,
+    T::Err: Display,
+{
+    type Err = ParseGridError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        GridHashMap::parse_with(Neighborhood::Square, input, |ch| {
+            ch.encode_utf8(&mut [0; 4]).parse().map(Some)
+        })
+    }
+}
+
 impl<T> GridHashMap<T>
 where
     T: Default + Clone + std::fmt::Display,
 {
+    /// Unbounded grid of the chars of the lines of input mapped to cells (None: no cell)
+    pub fn parse_with<E: Display>(
+        neighborhood: Neighborhood,
+        input: &str,
+        map: impl FnMut(char) -> Result<Option<T>, E>,
+    ) -> Result<Self, ParseGridError> {
+        let rows = grid_text::parse_rows(input, map)?;
+        Ok(GridHashMap::from_cells(
+            neighborhood,
+            rows.into_iter().enumerate().flat_map(|(y, row)| {
+                row.into_iter().enumerate().filter_map(move |(x, cell)| {
+                    let coor = Coor2DMut::new(x.try_into().unwrap(), y.try_into().unwrap());
+                    cell.map(|cell| (coor, cell))
+                })
+            }),
+        ))
+    }
+
+    /// Unbounded grid of the cells of the run-length format (see [`crate::grid_text`]), the
+    /// chars of the runs mapped to cells
+    pub fn from_run_length_str<E: Display>(
+        neighborhood: Neighborhood,
+        input: &str,
+        map: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseGridError> {
+        Ok(GridHashMap::from_cells(
+            neighborhood,
+            grid_text::parse_run_length(input, map)?,
+        ))
+    }
+
+    /// The cells mapped to chars in the run-length format (see [`crate::grid_text`]), a segment
+    /// per row and gap, so it is compact for sparse grids with large areas of equal cells
+    /// #Panics panics if a cell is mapped to a digit or a line break
+    pub fn to_run_length_string(&self, map: impl Fn(&T) -> char) -> String {
+        let cells = self
+            .data
+            .iter()
+            .map(|(coor, cell)| (coor.clone(), map(cell)))
+            .sorted_by_key(|(coor, _)| (coor.y, coor.x))
+            .collect_vec();
+        grid_text::to_run_length_string(&cells)
+    }
+
+    /// from cells (an unbounded grid)
+    pub fn from_cells(
+        neighborhood: Neighborhood,
+        cells: impl IntoIterator<Item = (Coor2DMut<isize>, T)>,
+    ) -> Self {
+        GridHashMap {
+            neighborhood,
+            topology: Topology::Bounded,
+            bounds: None,
+            data: cells.into_iter().collect(),
+            journal: None,
+        }
+    }
+
     /// from 1d vector
     pub fn from_1d_vec(neighborhood: Neighborhood, width: usize, data: Vec<T>) -> Self {
         debug_assert_eq!(
@@ -58,6 +190,8 @@ where
         );
         GridHashMap {
             neighborhood,
+            topology: Topology::Bounded,
+            bounds: None,
             data: data
                 .into_iter()
                 .enumerate()
@@ -68,14 +202,54 @@ where
                     (x, cell)
                 })
                 .collect(),
+            journal: None,
         }
     }
 
+    /// Limits the grid to the cells between `min` and `max` (both included, e.g. the result of
+    /// `get_min_max`), on a torus the neighbors and moves wrap around at the bounds
+    #[must_use]
+    pub fn with_bounds(
+        mut self,
+        (min, max): (Coor2DMut<isize>, Coor2DMut<isize>),
+        topology: Topology,
+    ) -> Self {
+        debug_assert!(
+            self.data
+                .keys()
+                .all(|coor| min.min(coor) == min && max.max(coor) == max),
+            "cells outside of min={min} max={max}"
+        );
+        self.bounds = Some((min, max));
+        self.topology = topology;
+        self
+    }
+
     /// get_neighborhood
     pub fn get_neighborhood(&self) -> Neighborhood {
         self.neighborhood
     }
 
+    /// get_topology (only used if there are bounds)
+    pub fn get_topology(&self) -> Topology {
+        self.topology
+    }
+
+    /// get min and max coor of the bounds (both included), None if the grid is unbounded
+    pub fn get_bounds(&self) -> Option<&(Coor2DMut<isize>, Coor2DMut<isize>)> {
+        self.bounds.as_ref()
+    }
+
+    /// Is x, y inside the bounds (always true if the grid is unbounded)
+    pub fn contains(&self, coor: &Coor2DMut<isize>) -> bool {
+        self.wrap(coor.clone()).as_ref() == Some(coor)
+    }
+
+    /// Coor inside the bounds: wrapped around on a torus, None if it is outside of a bounded grid
+    pub fn wrap(&self, coor: Coor2DMut<isize>) -> Option<Coor2DMut<isize>> {
+        grid_iteration::wrap_cell(self.topology, self.bounds.as_ref(), coor)
+    }
+
     /// get min coor
     pub fn get_min_max(&self) -> (Coor2DMut<isize>, Coor2DMut<isize>) {
         self.data.keys().fold(
@@ -99,12 +273,30 @@ where
 
     /// set new element on x, y and return old element
     pub fn set(&mut self, coor: Coor2DMut<isize>, new_value: T) -> Option<T> {
-        self.data.insert(coor, new_value)
+        debug_assert!(self.contains(&coor), "coor={coor} bounds={:?}", self.bounds);
+        let new = self.journal.is_some().then(|| new_value.clone());
+        let old = self.data.insert(coor.clone(), new_value);
+        if let Some(journal) = &mut self.journal {
+            journal.record(CellChange {
+                coor,
+                old: old.clone(),
+                new,
+            });
+        }
+        old
     }
 
     /// clear element on x, y and return old element
     pub fn remove(&mut self, coor: &Coor2DMut<isize>) -> Option<T> {
-        self.data.remove(coor)
+        let old = self.data.remove(coor);
+        if let (Some(journal), Some(old)) = (&mut self.journal, &old) {
+            journal.record(CellChange {
+                coor: coor.clone(),
+                old: Some(old.clone()),
+                new: None,
+            });
+        }
+        old
     }
 
     /// return all indexes
@@ -112,13 +304,33 @@ where
         self.data.keys().cloned()
     }
 
-    /// return all neighbor indexes (based on neighborhood)
+    /// return all neighbor indexes (based on topology, bounds and neighborhood)
     pub fn neighborhood_cell_indexes<'a>(
         &self,
         coor: &'a Coor2DMut<isize>,
     ) -> impl Iterator<Item = Coor2DMut<isize>> + 'a {
-        grid_iteration::all_adjacent_cells(self.neighborhood)
-            .map(|direction| coor.clone() + direction.diff_coor())
+        let (topology, bounds) = (self.topology, self.bounds.clone());
+        grid_iteration::all_adjacent_cells(self.neighborhood, coor.y).filter_map(move |diff| {
+            grid_iteration::wrap_cell(topology, bounds.as_ref(), coor.clone() + diff)
+        })
+    }
+
+    /// return adjacent cell in direction (based on topology and bounds)
+    pub fn adjacent_cell(
+        &self,
+        coor: &Coor2DMut<isize>,
+        direction: Direction,
+    ) -> Option<Coor2DMut<isize>> {
+        self.moved_cell(coor, &direction.diff_coor())
+    }
+
+    /// return cell moved by `diff` (based on topology and bounds)
+    pub fn moved_cell(
+        &self,
+        coor: &Coor2DMut<isize>,
+        diff: &Coor2DMut<isize>,
+    ) -> Option<Coor2DMut<isize>> {
+        self.wrap(coor.clone() + diff.clone())
     }
 
     fn map_indexes_to_cells(
@@ -138,17 +350,22 @@ where
         self.map_indexes_to_cells(self.all_indexes())
     }
 
-    /// return all neighbor elements (based on topology and neighborhood)
+    /// return all neighbor elements (based on topology, bounds and neighborhood)
     pub fn neighborhood_cells<'a>(
         &'a self,
         coor: &'a Coor2DMut<isize>,
-    ) -> impl Iterator<Item = (Coor2DMut<isize>, Option<&T>)> + 'a {
+    ) -> impl Iterator<Item = (Coor2DMut<isize>, Option<&'a T>)> + 'a {
         self.map_indexes_to_cells(self.neighborhood_cell_indexes(coor))
     }
 
+    /// Bounds or (if unbounded) min and max of the existing cells
+    fn print_min_max(&self) -> (Coor2DMut<isize>, Coor2DMut<isize>) {
+        self.bounds.clone().unwrap_or_else(|| self.get_min_max())
+    }
+
     /// Print grid
     pub fn print(&self, default: &char) {
-        let min_max = self.get_min_max();
+        let min_max = self.print_min_max();
         //println!("min: {:?}, max: {:?}", min_max.0, min_max.1);
         for y in min_max.0.y..=min_max.1.y {
             for x in min_max.0.x..=min_max.1.x {
@@ -164,7 +381,7 @@ where
 
     /// Print grid
     pub fn print_with_mapping(&self, map_fn: impl Fn(Option<&T>) -> char) {
-        let min_max = self.get_min_max();
+        let min_max = self.print_min_max();
         //println!("min: {:?}, max: {:?}", min_max.0, min_max.1);
         for y in min_max.0.y..=min_max.1.y {
             for x in min_max.0.x..=min_max.1.x {
@@ -175,3 +392,174 @@ where
         }
     }
 }
+
+/// Differences and the change journal (see [`crate::grid_diff`]), an empty cell is missing
+impl<T> GridHashMap<T>
+where
+    T: Default + Clone + std::fmt::Display,
+{
+    /// Changes from this grid to other (in reading order)
+    pub fn diff(&self, other: &Self) -> GridDiff<Coor2DMut<isize>, T>
+    where
+        T: PartialEq,
+    {
+        let mut changes = self
+            .data
+            .iter()
+            .filter(|(coor, cell)| other.get(coor) != Some(cell))
+            .map(|(coor, cell)| CellChange {
+                coor: coor.clone(),
+                old: Some(cell.clone()),
+                new: other.get(coor).cloned(),
+            })
+            .chain(
+                other
+                    .data
+                    .iter()
+                    .filter(|(coor, _)| !self.data.contains_key(coor))
+                    .map(|(coor, cell)| CellChange {
+                        coor: coor.clone(),
+                        old: None,
+                        new: Some(cell.clone()),
+                    }),
+            )
+            .collect_vec();
+        changes.sort_by_key(|change| (change.coor.y, change.coor.x));
+        let mut diff = GridDiff::new();
+        for change in changes {
+            diff.push(change);
+        }
+        diff
+    }
+
+    /// Sets or removes the new cells of the changes (e.g. the steps of a journal to replay them)
+    pub fn apply_diff(&mut self, diff: &GridDiff<Coor2DMut<isize>, T>) {
+        for change in diff.changes() {
+            match &change.new {
+                Some(new) => {
+                    self.set(change.coor.clone(), new.clone());
+                }
+                None => {
+                    self.remove(&change.coor);
+                }
+            }
+        }
+    }
+
+    /// Starts recording the cells changed by `set` and `remove` (in steps, see `end_step`)
+    pub fn start_journal(&mut self) {
+        self.journal = Some(ChangeJournal::new());
+    }
+
+    /// Stops recording and returns the journal
+    pub fn take_journal(&mut self) -> Option<ChangeJournal<Coor2DMut<isize>, T>> {
+        self.journal.take()
+    }
+
+    /// `get_journal`
+    pub fn get_journal(&self) -> Option<&ChangeJournal<Coor2DMut<isize>, T>> {
+        self.journal.as_ref()
+    }
+
+    /// Ends the current step of the journal
+    pub fn end_step(&mut self) {
+        if let Some(journal) = &mut self.journal {
+            journal.end_step();
+        }
+    }
+
+    /// Reverts and removes the current step if it has changes, otherwise the last ended step of
+    /// the journal, returns the reverted step
+    pub fn undo_step(&mut self) -> Option<GridDiff<Coor2DMut<isize>, T>> {
+        let mut journal = self.journal.take()?;
+        let step = journal.pop_step();
+        if let Some(step) = &step {
+            self.apply_diff(&step.reversed());
+        }
+        self.journal = Some(journal);
+        step
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_trait::Grid;
+    use crate::grid_types::HexLayout;
+
+    fn coor(x: isize, y: isize) -> Coor2DMut<isize> {
+        Coor2DMut::new(x, y)
+    }
+
+    fn neighbors(grid: &GridHashMap<char>, center: &Coor2DMut<isize>) -> Vec<Coor2DMut<isize>> {
+        grid.neighborhood_cell_indexes(center)
+            .sorted_by_key(|coor| (coor.y, coor.x))
+            .collect()
+    }
+
+    #[test]
+    fn unbounded() {
+        let grid = GridHashMap::from_newline_separated_string(Neighborhood::Orthogonal, "ab\ncd\n");
+        assert_eq!(grid.get_bounds(), None);
+        assert_eq!(
+            neighbors(&grid, &coor(0, 0)),
+            vec![coor(0, -1), coor(-1, 0), coor(1, 0), coor(0, 1)]
+        );
+        assert_eq!(
+            grid.adjacent_cell(&coor(0, 0), Direction::West),
+            Some(coor(-1, 0))
+        );
+        assert!(grid.contains(&coor(-10, 10)));
+
+        let hex =
+            GridHashMap::<char>::from_1d_vec(Neighborhood::Hex(HexLayout::OddRows), 1, vec![]);
+        assert_eq!(
+            neighbors(&hex, &coor(0, -1)),
+            vec![
+                coor(0, -2),
+                coor(1, -2),
+                coor(-1, -1),
+                coor(1, -1),
+                coor(0, 0),
+                coor(1, 0)
+            ]
+        );
+        let knight = GridHashMap::<char>::from_1d_vec(Neighborhood::Knight, 1, vec![]);
+        assert_eq!(neighbors(&knight, &coor(0, 0))[0], coor(-1, -2));
+    }
+
+    #[test]
+    fn bounded_and_torus() {
+        let grid = GridHashMap::from_newline_separated_string(Neighborhood::Square, "ab.\ncd.\n");
+        let bounds = grid.get_min_max();
+
+        let bounded = grid.clone().with_bounds(bounds.clone(), Topology::Bounded);
+        assert_eq!(
+            neighbors(&bounded, &coor(0, 0)),
+            vec![coor(1, 0), coor(0, 1), coor(1, 1)]
+        );
+        assert_eq!(bounded.adjacent_cell(&coor(2, 1), Direction::East), None);
+        assert!(!bounded.contains(&coor(3, 0)));
+
+        let mut torus = grid.with_bounds(bounds, Topology::Torus);
+        assert_eq!(neighbors(&torus, &coor(0, 0)).len(), 8);
+        assert_eq!(
+            torus.adjacent_cell(&coor(2, 1), Direction::East),
+            Some(coor(0, 1))
+        );
+        assert_eq!(
+            torus.adjacent_cell(&coor(0, 0), Direction::NorthWest),
+            Some(coor(2, 1))
+        );
+        assert_eq!(
+            torus.moved_cell(&coor(1, 1), &coor(-4, 3)),
+            Some(coor(0, 0))
+        );
+
+        // wrapping neighbors are used by the algorithms too
+        torus.remove(&coor(1, 0));
+        torus.remove(&coor(1, 1));
+        let component = torus.flood_fill(&coor(0, 0), |_| true).unwrap();
+        assert_eq!(component.size(), 4);
+    }
+}
@@ -0,0 +1,255 @@
+//! 3D grid based on a hash map
+
+use fxhash::{FxHashMap, FxHashSet};
+
+use crate::grid_iteration;
+use crate::grid_regions::{self, Component, Components};
+use crate::grid_types::{Coor3DMut, Neighborhood3D};
+
+/// `GridHashMap3D`
+#[allow(missing_docs)]
+#[derive(Builder, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GridHashMap3D<T>
+where
+    T: Default + Clone + std::fmt::Display,
+{
+    #[builder(default = "Neighborhood3D::Face")]
+    neighborhood: Neighborhood3D,
+
+    #[builder(default = "FxHashMap::default()")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            with = "crate::grid_text::serde_cells",
+            bound(
+                serialize = "T: serde::Serialize",
+                deserialize = "T: serde::Deserialize<'de>"
+            )
+        )
+    )]
+    data: FxHashMap<Coor3DMut<isize>, T>,
+}
+
+impl<T> GridHashMap3D<T>
+where
+    T: Default + Clone + std::fmt::Display,
+{
+    /// from cells
+    pub fn from_cells(
+        neighborhood: Neighborhood3D,
+        cells: impl IntoIterator<Item = (Coor3DMut<isize>, T)>,
+    ) -> Self {
+        GridHashMap3D {
+            neighborhood,
+            data: cells.into_iter().collect(),
+        }
+    }
+
+    /// get_neighborhood
+    pub fn get_neighborhood(&self) -> Neighborhood3D {
+        self.neighborhood
+    }
+
+    /// number of cells
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// no cells
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// get min and max coor (bounding box of all cells)
+    pub fn get_min_max(&self) -> (Coor3DMut<isize>, Coor3DMut<isize>) {
+        self.data.keys().fold(
+            (
+                Coor3DMut::new(isize::MAX, isize::MAX, isize::MAX),
+                Coor3DMut::new(isize::MIN, isize::MIN, isize::MIN),
+            ),
+            |(min_coor, max_coor), coor| (min_coor.min(coor), max_coor.max(coor)),
+        )
+    }
+
+    /// get reference to element on coor
+    pub fn get(&self, coor: &Coor3DMut<isize>) -> Option<&T> {
+        self.data.get(coor)
+    }
+
+    /// get mutable reference element on coor
+    pub fn get_mut(&mut self, coor: &Coor3DMut<isize>) -> Option<&mut T> {
+        self.data.get_mut(coor)
+    }
+
+    /// set new element on coor and return old element
+    pub fn set(&mut self, coor: Coor3DMut<isize>, new_value: T) -> Option<T> {
+        self.data.insert(coor, new_value)
+    }
+
+    /// clear element on coor and return old element
+    pub fn remove(&mut self, coor: &Coor3DMut<isize>) -> Option<T> {
+        self.data.remove(coor)
+    }
+
+    /// return all indexes
+    pub fn all_indexes(&self) -> impl Iterator<Item = Coor3DMut<isize>> + '_ {
+        self.data.keys().cloned()
+    }
+
+    /// return all neighbor indexes (based on neighborhood)
+    pub fn neighborhood_cell_indexes<'a>(
+        &self,
+        coor: &'a Coor3DMut<isize>,
+    ) -> impl Iterator<Item = Coor3DMut<isize>> + 'a {
+        grid_iteration::all_adjacent_cells_3d(self.neighborhood).map(|diff| coor.clone() + diff)
+    }
+
+    fn map_indexes_to_cells(
+        &self,
+        it: impl Iterator<Item = Coor3DMut<isize>>,
+    ) -> impl Iterator<Item = (Coor3DMut<isize>, Option<&T>)> {
+        it.map(|coor| {
+            let cell = self.get(&coor);
+            (coor, cell)
+        })
+    }
+
+    /// all data
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.values()
+    }
+
+    /// return all elements
+    pub fn all_cells(&self) -> impl Iterator<Item = (Coor3DMut<isize>, Option<&T>)> {
+        self.map_indexes_to_cells(self.all_indexes())
+    }
+
+    /// return all neighbor elements (based on neighborhood)
+    pub fn neighborhood_cells<'a>(
+        &'a self,
+        coor: &'a Coor3DMut<isize>,
+    ) -> impl Iterator<Item = (Coor3DMut<isize>, Option<&'a T>)> + 'a {
+        self.map_indexes_to_cells(self.neighborhood_cell_indexes(coor))
+    }
+
+    /// All coordinates of the bounding box (including empty ones) grown by `margin` in every direction
+    pub fn all_indexes_in_bounding_box(
+        &self,
+        margin: isize,
+    ) -> impl Iterator<Item = Coor3DMut<isize>> {
+        let (min, max) = self.get_min_max();
+        (min.z - margin..=max.z + margin).flat_map(move |z| {
+            (min.y - margin..=max.y + margin).flat_map(move |y| {
+                (min.x - margin..=max.x + margin).map(move |x| Coor3DMut::new(x, y, z))
+            })
+        })
+    }
+
+    /// All existing cells matching `passable` that are reachable from `start`
+    /// (None if `start` does not exist or is not passable)
+    pub fn flood_fill(
+        &self,
+        start: &Coor3DMut<isize>,
+        mut passable: impl FnMut((&Coor3DMut<isize>, &T)) -> bool,
+    ) -> Option<Component<Coor3DMut<isize>>> {
+        if !passable((start, self.get(start)?)) {
+            return None;
+        }
+        let cells = grid_regions::flood_fill(start.clone(), |coor| {
+            self.neighborhood_cells(coor)
+                .filter_map(|(neighbor, cell)| {
+                    cell.filter(|cell| passable((&neighbor, cell)))?;
+                    Some(neighbor)
+                })
+                .collect::<Vec<_>>()
+        });
+        Some(Component::new(0, cells))
+    }
+
+    /// Connected regions of the existing cells matching `predicate` (ids in order of their
+    /// first cell by z, y, x)
+    pub fn connected_components(
+        &self,
+        mut predicate: impl FnMut((&Coor3DMut<isize>, &T)) -> bool,
+    ) -> Components<Coor3DMut<isize>> {
+        let mut matching = self
+            .data
+            .iter()
+            .filter(|(coor, cell)| predicate((coor, cell)))
+            .map(|(coor, _)| coor.clone())
+            .collect::<Vec<_>>();
+        matching.sort_unstable_by_key(|coor| (coor.z, coor.y, coor.x));
+        let matching_set = matching.iter().cloned().collect::<FxHashSet<_>>();
+        let components = grid_regions::connected_components(matching, |coor| {
+            self.neighborhood_cell_indexes(coor)
+                .filter(|neighbor| matching_set.contains(neighbor))
+                .collect::<Vec<_>>()
+        });
+        Components::new(
+            components
+                .into_iter()
+                .enumerate()
+                .map(|(id, cells)| Component::new(id, cells))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cells() {
+        let mut grid = GridHashMap3D::from_cells(
+            Neighborhood3D::Face,
+            [
+                (Coor3DMut::new(1, 1, 1), 'a'),
+                (Coor3DMut::new(1, 1, 2), 'b'),
+            ],
+        );
+        grid.set(Coor3DMut::new(-3, 0, 5), 'c');
+        assert_eq!(grid.len(), 3);
+        assert_eq!(
+            grid.get_min_max(),
+            (Coor3DMut::new(-3, 0, 1), Coor3DMut::new(1, 1, 5))
+        );
+        assert_eq!(
+            grid.neighborhood_cells(&Coor3DMut::new(1, 1, 1))
+                .filter(|(_, cell)| cell.is_some())
+                .count(),
+            1
+        );
+        assert_eq!(grid.all_indexes_in_bounding_box(1).count(), 7 * 4 * 7);
+
+        let components = grid.connected_components(|_| true);
+        assert_eq!(components.sizes().collect::<Vec<_>>(), [2, 1]);
+        assert_eq!(components.id(&Coor3DMut::new(-3, 0, 5)), Some(1));
+        assert_eq!(grid.remove(&Coor3DMut::new(1, 1, 2)), Some('b'));
+        assert_eq!(
+            grid.flood_fill(&Coor3DMut::new(1, 1, 1), |_| true)
+                .unwrap()
+                .size(),
+            1
+        );
+    }
+
+    #[test]
+    fn neighborhoods() {
+        let mut grid = GridHashMap3DBuilder::default()
+            .neighborhood(Neighborhood3D::Edge)
+            .build()
+            .unwrap();
+        grid.set(Coor3DMut::new(0, 0, 0), 1);
+        grid.set(Coor3DMut::new(1, 1, 0), 2);
+        grid.set(Coor3DMut::new(2, 2, 2), 3);
+        // (1, 1, 0) and (2, 2, 2) only share a vertex
+        assert_eq!(grid.connected_components(|_| true).len(), 2);
+        assert_eq!(
+            grid.neighborhood_cell_indexes(&Coor3DMut::new(0, 0, 0))
+                .count(),
+            18
+        );
+    }
+}
@@ -1,11 +1,12 @@
 //! Manipulating 2d grids
 
 use super::grid_types::{
-    Coor2D, Coor2DIndex, Direction, Direction::*, Neighborhood, Neighborhood::*, Topology,
-    Topology::*,
+    Coor2D, Coor2DIndex, Coor2DMut, Coor3D, Coor3DIndex, Coor3DMut, Direction, Direction::*,
+    HexDirection, Neighborhood, Neighborhood::*, Neighborhood3D, Topology, Topology::*,
 };
 
-/// Get the adjacent point to a point in a given direction
+/// Get the adjacent point to a point in a given direction (see [`crate::grid_cube::CubeNet::adjacent_cell`] for the
+/// direction after the step on a cube)
 pub(crate) fn adjacent_cell(
     t: Topology,
     width: Coor2DIndex,
@@ -13,6 +14,9 @@ pub(crate) fn adjacent_cell(
     index: Coor2D,
     d: Direction,
 ) -> Option<Coor2D> {
+    if let CubeNet(net) = t {
+        return net.adjacent_cell(&index, d).map(|(coor, _)| coor);
+    }
     let (x, y) = (index.x, index.y);
     match d {
         NorthEast => adjacent_cell(t, width, height, index, North)
@@ -53,10 +57,76 @@ pub(crate) fn adjacent_cell(
 
                 _ => unreachable!(), // already handled above
             },
+            CubeNet(_) => unreachable!(), // already handled above
         },
     }
 }
 
+/// Get the point moved by a difference vector (on a torus it wraps around, otherwise None if it
+/// leaves the grid)
+pub(crate) fn moved_cell(
+    t: Topology,
+    width: Coor2DIndex,
+    height: Coor2DIndex,
+    index: &Coor2D,
+    diff: &Coor2DMut<isize>,
+) -> Option<Coor2D> {
+    match t {
+        Bounded => {
+            let x = index.x.checked_add_signed(diff.x).filter(|x| *x < width)?;
+            let y = index.y.checked_add_signed(diff.y).filter(|y| *y < height)?;
+            Some(Coor2D::new(x, y))
+        }
+        Torus => {
+            let wrap = |value: Coor2DIndex, diff: isize, size: Coor2DIndex| {
+                let size = isize::try_from(size).unwrap();
+                (isize::try_from(value).unwrap() + diff)
+                    .rem_euclid(size)
+                    .unsigned_abs()
+            };
+            Some(Coor2D::new(
+                wrap(index.x, diff.x, width),
+                wrap(index.y, diff.y, height),
+            ))
+        }
+        CubeNet(net) => net.moved_cell(index, diff),
+    }
+}
+
+/// Get a point inside the bounds (min and max are both included) of a grid with signed
+/// coordinates, on a torus the point wraps around, otherwise None if it is outside
+/// (no bounds: every point is inside, a cube net is not folded: only the points on its faces are
+/// inside)
+pub(crate) fn wrap_cell(
+    t: Topology,
+    bounds: Option<&(Coor2DMut<isize>, Coor2DMut<isize>)>,
+    index: Coor2DMut<isize>,
+) -> Option<Coor2DMut<isize>> {
+    let Some((min, max)) = bounds else {
+        return Some(index);
+    };
+    match t {
+        Bounded => (min.x <= index.x && index.x <= max.x && min.y <= index.y && index.y <= max.y)
+            .then_some(index),
+        Torus => {
+            let wrap = |value: isize, min: isize, max: isize| {
+                min + (value - min).rem_euclid(max - min + 1)
+            };
+            Some(Coor2DMut::new(
+                wrap(index.x, min.x, max.x),
+                wrap(index.y, min.y, max.y),
+            ))
+        }
+        CubeNet(net) => {
+            let coor = Coor2D::new(
+                (index.x - min.x).try_into().ok()?,
+                (index.y - min.y).try_into().ok()?,
+            );
+            net.contains(&coor).then_some(index)
+        }
+    }
+}
+
 /// Is a given point on an edge of a grid
 pub(crate) fn is_edge(
     t: Topology,
@@ -84,14 +154,42 @@ pub(crate) fn all_cells(width: Coor2DIndex, height: Coor2DIndex) -> impl Iterato
     (0..width).flat_map(move |x| (0..height).map(move |y| Coor2D::new(x, y)))
 }
 
-/// Returns an iterator over the directions for given neighborhood type
-pub(crate) fn all_adjacent_cells(n: Neighborhood) -> impl Iterator<Item = Direction> {
+/// Returns an iterator over the difference vectors for given neighborhood type around a point
+/// in row y (the offset hex layouts depend on the row)
+pub(crate) fn all_adjacent_cells(
+    n: Neighborhood,
+    y: isize,
+) -> impl Iterator<Item = Coor2DMut<isize>> {
+    let directions = |directions: &[Direction]| {
+        directions
+            .iter()
+            .map(Direction::diff_coor)
+            .collect::<Vec<_>>()
+    };
     match n {
-        Orthogonal => vec![North, South, East, West],
-        Diagonal => vec![NorthWest, NorthEast, SouthEast, SouthWest],
-        Square => vec![
+        Orthogonal => directions(&[North, South, East, West]),
+        Diagonal => directions(&[NorthWest, NorthEast, SouthEast, SouthWest]),
+        Square => directions(&[
             North, NorthEast, East, SouthEast, South, SouthWest, West, NorthWest,
-        ],
+        ]),
+        Hex(layout) => HexDirection::ALL
+            .iter()
+            .map(|direction| direction.diff_coor(layout, y))
+            .collect(),
+        Knight => [
+            (1, -2),
+            (2, -1),
+            (2, 1),
+            (1, 2),
+            (-1, 2),
+            (-2, 1),
+            (-2, -1),
+            (-1, -2),
+        ]
+        .into_iter()
+        .map(Coor2DMut::from)
+        .collect(),
+        Offsets(offsets) => offsets.iter().copied().map(Coor2DMut::from).collect(),
     }
     .into_iter()
 }
@@ -104,8 +202,52 @@ pub(crate) fn neighborhood_cells(
     index: Coor2D,
     n: Neighborhood,
 ) -> impl Iterator<Item = Coor2D> {
-    all_adjacent_cells(n)
-        .filter_map(move |direction| adjacent_cell(t, width, height, index.clone(), direction))
+    all_adjacent_cells(n, isize::try_from(index.y).unwrap())
+        .filter_map(move |diff| moved_cell(t, width, height, &index, &diff))
+}
+
+/// Returns an iterator over the difference vectors for given 3D neighborhood type
+pub(crate) fn all_adjacent_cells_3d(n: Neighborhood3D) -> impl Iterator<Item = Coor3DMut<isize>> {
+    // number of axes that differ: 1 shares a face, 2 an edge, 3 a vertex
+    let max_changed_axes = match n {
+        Neighborhood3D::Face => 1,
+        Neighborhood3D::Edge => 2,
+        Neighborhood3D::Vertex => 3,
+    };
+    (-1..=1)
+        .flat_map(|z| (-1..=1).flat_map(move |y| (-1..=1).map(move |x| Coor3DMut::new(x, y, z))))
+        .filter(move |diff| {
+            let changed_axes = diff.abs();
+            changed_axes > 0 && changed_axes <= max_changed_axes
+        })
+}
+
+/// Returns an iterator over the points of a 3D grid
+pub(crate) fn all_cells_3d(
+    width: Coor3DIndex,
+    height: Coor3DIndex,
+    depth: Coor3DIndex,
+) -> impl Iterator<Item = Coor3D> {
+    (0..depth).flat_map(move |z| {
+        (0..height).flat_map(move |y| (0..width).map(move |x| Coor3D::new(x, y, z)))
+    })
+}
+
+/// Returns an iterator over the points in a 3D neighborhood around a point (inside the bounds)
+pub(crate) fn neighborhood_cells_3d(
+    width: Coor3DIndex,
+    height: Coor3DIndex,
+    depth: Coor3DIndex,
+    index: &Coor3D,
+    n: Neighborhood3D,
+) -> impl Iterator<Item = Coor3D> {
+    let (x, y, z) = index.to_tuple();
+    all_adjacent_cells_3d(n).filter_map(move |diff| {
+        let x = x.checked_add_signed(diff.x).filter(|x| *x < width)?;
+        let y = y.checked_add_signed(diff.y).filter(|y| *y < height)?;
+        let z = z.checked_add_signed(diff.z).filter(|z| *z < depth)?;
+        Some(Coor3D::new(x, y, z))
+    })
 }
 
 #[cfg(test)]
@@ -113,6 +255,7 @@ mod tests {
     use std::collections::HashSet;
 
     use super::*;
+    use crate::grid_types::HexLayout;
 
     #[test]
     fn adjacent_bounded() {
@@ -174,6 +317,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn wrap_signed() {
+        let bounds = (Coor2DMut::new(-1, 1), Coor2DMut::new(1, 3));
+        let coor = |x, y| Coor2DMut::<isize>::new(x, y);
+        assert_eq!(wrap_cell(Bounded, None, coor(-5, 7)), Some(coor(-5, 7)));
+        assert_eq!(wrap_cell(Torus, None, coor(-5, 7)), Some(coor(-5, 7)));
+        assert_eq!(
+            wrap_cell(Bounded, Some(&bounds), coor(1, 3)),
+            Some(coor(1, 3))
+        );
+        assert_eq!(wrap_cell(Bounded, Some(&bounds), coor(2, 3)), None);
+        assert_eq!(wrap_cell(Bounded, Some(&bounds), coor(0, 0)), None);
+        assert_eq!(
+            wrap_cell(Torus, Some(&bounds), coor(2, 3)),
+            Some(coor(-1, 3))
+        );
+        assert_eq!(
+            wrap_cell(Torus, Some(&bounds), coor(-2, 0)),
+            Some(coor(1, 3))
+        );
+        assert_eq!(
+            wrap_cell(Torus, Some(&bounds), coor(7, -4)),
+            Some(coor(1, 2))
+        );
+    }
+
     #[test]
     fn edge() {
         assert!(is_edge(Bounded, 3, 3, &Coor2D::new(1, 0)));
@@ -211,6 +380,114 @@ mod tests {
         );
     }
 
+    #[test]
+    fn neighborhoods_hex_knight() {
+        let cells = |n, x, y| {
+            neighborhood_cells(Bounded, 5, 5, Coor2D::new(x, y), n).collect::<HashSet<Coor2D>>()
+        };
+        let set = |coors: &[(usize, usize)]| {
+            coors
+                .iter()
+                .copied()
+                .map(Coor2D::from)
+                .collect::<HashSet<_>>()
+        };
+        assert_eq!(
+            cells(Hex(HexLayout::Axial), 2, 2),
+            set(&[(3, 2), (1, 2), (3, 1), (2, 1), (2, 3), (1, 3)])
+        );
+        assert_eq!(
+            cells(Hex(HexLayout::OddRows), 2, 2),
+            set(&[(3, 2), (1, 2), (1, 1), (2, 1), (1, 3), (2, 3)])
+        );
+        assert_eq!(
+            cells(Hex(HexLayout::OddRows), 2, 1),
+            set(&[(3, 1), (1, 1), (2, 0), (3, 0), (2, 2), (3, 2)])
+        );
+        assert_eq!(
+            cells(Hex(HexLayout::EvenRows), 2, 1),
+            cells(Hex(HexLayout::OddRows), 2, 2)
+                .into_iter()
+                .map(|coor| Coor2D::new(coor.x, coor.y - 1))
+                .collect()
+        );
+        assert_eq!(cells(Hex(HexLayout::OddRows), 0, 0).len(), 2);
+
+        assert_eq!(cells(Knight, 2, 2).len(), 8);
+        assert_eq!(cells(Knight, 0, 0), set(&[(1, 2), (2, 1)]));
+        assert_eq!(
+            neighborhood_cells(Torus, 5, 5, Coor2D::new(0, 0), Knight).count(),
+            8
+        );
+        assert_eq!(
+            cells(Offsets(&[(0, 2), (0, -2), (3, 3)]), 1, 1),
+            set(&[(1, 3), (4, 4)])
+        );
+    }
+
+    #[test]
+    fn hex_directions() {
+        for layout in [HexLayout::Axial, HexLayout::OddRows, HexLayout::EvenRows] {
+            for y in [-3, 0, 1, 4] {
+                let coor = Coor2DMut::new(2, y);
+                for direction in HexDirection::ALL {
+                    let neighbor = coor.clone() + direction.diff_coor(layout, y);
+                    assert_eq!(layout.distance(&coor, &neighbor), 1);
+                    // going back leads to the start
+                    let back = direction.rotate(180).diff_coor(layout, neighbor.y);
+                    assert_eq!(neighbor + back, coor);
+                }
+            }
+        }
+        assert_eq!(HexDirection::East.rotate(60), HexDirection::SouthEast);
+        assert_eq!(HexDirection::East.rotate(-120), HexDirection::NorthWest);
+        assert_eq!(
+            HexLayout::OddRows.distance(&Coor2DMut::new(0, 0), &Coor2DMut::new(3, 4)),
+            5
+        );
+    }
+
+    #[test]
+    fn neighborhoods_3d() {
+        assert_eq!(all_adjacent_cells_3d(Neighborhood3D::Face).count(), 6);
+        assert_eq!(all_adjacent_cells_3d(Neighborhood3D::Edge).count(), 18);
+        assert_eq!(all_adjacent_cells_3d(Neighborhood3D::Vertex).count(), 26);
+        assert_eq!(all_cells_3d(2, 3, 4).count(), 24);
+        assert_eq!(
+            neighborhood_cells_3d(3, 3, 3, &Coor3D::new(0, 0, 0), Neighborhood3D::Vertex).count(),
+            7
+        );
+        assert_eq!(
+            neighborhood_cells_3d(3, 3, 3, &Coor3D::new(1, 1, 1), Neighborhood3D::Edge).count(),
+            18
+        );
+        assert_eq!(
+            neighborhood_cells_3d(3, 3, 3, &Coor3D::new(2, 1, 1), Neighborhood3D::Face)
+                .collect::<HashSet<Coor3D>>(),
+            HashSet::from([
+                Coor3D::new(1, 1, 1),
+                Coor3D::new(2, 0, 1),
+                Coor3D::new(2, 2, 1),
+                Coor3D::new(2, 1, 0),
+                Coor3D::new(2, 1, 2),
+            ]),
+        );
+    }
+
+    #[test]
+    fn manhattan_distance_3d_test() {
+        assert_eq!(
+            Coor3DMut::new(1, -2, 3).manhattan_distance(&Coor3DMut::new(-1, 0, 0)),
+            7
+        );
+        assert_eq!(Coor3DMut::new(1, -2, 3).abs(), 6);
+        assert_eq!(
+            Coor3DMut::new(1, 2, 3) + Coor3DMut::new(1, 1, 1),
+            Coor3DMut::new(2, 3, 4)
+        );
+        assert_eq!(Coor3DMut::new(1, 2, 3).to_string(), "(1,2,3)");
+    }
+
     #[test]
     fn manhattan_distance_test() {
         assert_eq!(
@@ -0,0 +1,345 @@
+//! Shortest paths (BFS, Dijkstra, A*) over any graph given by a successor function,
+//! the grids offer them with their topology and neighborhood (e.g. `Grid::dijkstra`)
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+
+use fxhash::{FxBuildHasher, FxHashMap};
+use indexmap::map::Entry;
+use indexmap::IndexMap;
+
+/// Found path (from the start to the goal, both included) and its cost
+pub type PathResult<C> = Option<(Vec<C>, usize)>;
+
+/// Visited nodes with their cost and the index of their predecessor
+struct Visited<C> {
+    nodes: IndexMap<C, (usize, Option<usize>), FxBuildHasher>,
+}
+
+impl<C: Clone + Eq + Hash> Visited<C> {
+    fn new() -> Self {
+        Visited {
+            nodes: IndexMap::default(),
+        }
+    }
+
+    /// Stores the node if it is new or cheaper than before, returns its index in that case
+    fn improve(&mut self, node: C, cost: usize, parent: Option<usize>) -> Option<usize> {
+        match self.nodes.entry(node) {
+            Entry::Occupied(entry) if entry.get().0 <= cost => None,
+            Entry::Occupied(mut entry) => {
+                entry.insert((cost, parent));
+                Some(entry.index())
+            }
+            Entry::Vacant(entry) => {
+                let index = entry.index();
+                entry.insert((cost, parent));
+                Some(index)
+            }
+        }
+    }
+
+    fn get(&self, index: usize) -> (&C, usize, Option<usize>) {
+        let (node, &(cost, parent)) = self.nodes.get_index(index).expect("visited node");
+        (node, cost, parent)
+    }
+
+    fn path(&self, mut index: usize) -> (Vec<C>, usize) {
+        let (node, cost, mut parent) = self.get(index);
+        let mut path = vec![node.clone()];
+        while let Some(parent_index) = parent {
+            index = parent_index;
+            let (node, _, next_parent) = self.get(index);
+            path.push(node.clone());
+            parent = next_parent;
+        }
+        path.reverse();
+        (path, cost)
+    }
+
+    fn distances(self) -> FxHashMap<C, usize> {
+        self.nodes
+            .into_iter()
+            .map(|(node, (cost, _))| (node, cost))
+            .collect()
+    }
+}
+
+/// Breadth first search from any of the starts (every step costs 1)
+pub fn bfs<C, I>(
+    starts: impl IntoIterator<Item = C>,
+    successors: impl FnMut(&C) -> I,
+    is_goal: impl FnMut(&C) -> bool,
+) -> PathResult<C>
+where
+    C: Clone + Eq + Hash,
+    I: IntoIterator<Item = C>,
+{
+    let (goal, visited) = breadth_first(starts, successors, is_goal);
+    goal.map(|index| visited.path(index))
+}
+
+/// Number of steps from the nearest start to every reachable node
+pub fn bfs_all<C, I>(
+    starts: impl IntoIterator<Item = C>,
+    successors: impl FnMut(&C) -> I,
+) -> FxHashMap<C, usize>
+where
+    C: Clone + Eq + Hash,
+    I: IntoIterator<Item = C>,
+{
+    breadth_first(starts, successors, |_| false).1.distances()
+}
+
+/// Breadth first search, returns the index of the reached goal and all visited nodes
+fn breadth_first<C, I>(
+    starts: impl IntoIterator<Item = C>,
+    mut successors: impl FnMut(&C) -> I,
+    mut is_goal: impl FnMut(&C) -> bool,
+) -> (Option<usize>, Visited<C>)
+where
+    C: Clone + Eq + Hash,
+    I: IntoIterator<Item = C>,
+{
+    let mut visited = Visited::new();
+    let mut queue = starts
+        .into_iter()
+        .filter_map(|start| visited.improve(start, 0, None))
+        .collect::<VecDeque<_>>();
+    while let Some(index) = queue.pop_front() {
+        let (node, cost, _) = visited.get(index);
+        if is_goal(node) {
+            return (Some(index), visited);
+        }
+        for successor in successors(node) {
+            if !visited.nodes.contains_key(&successor) {
+                queue.extend(visited.improve(successor, cost + 1, Some(index)));
+            }
+        }
+    }
+    (None, visited)
+}
+
+/// Dijkstra from any of the starts, `successors` returns the neighbors with the cost of the step
+pub fn dijkstra<C, I>(
+    starts: impl IntoIterator<Item = C>,
+    successors: impl FnMut(&C) -> I,
+    is_goal: impl FnMut(&C) -> bool,
+) -> PathResult<C>
+where
+    C: Clone + Eq + Hash,
+    I: IntoIterator<Item = (C, usize)>,
+{
+    astar(starts, successors, |_| 0, is_goal)
+}
+
+/// Cost from the nearest start to every reachable node
+pub fn dijkstra_all<C, I>(
+    starts: impl IntoIterator<Item = C>,
+    successors: impl FnMut(&C) -> I,
+) -> FxHashMap<C, usize>
+where
+    C: Clone + Eq + Hash,
+    I: IntoIterator<Item = (C, usize)>,
+{
+    search(starts, successors, |_| 0, |_| false).1.distances()
+}
+
+/// A* from any of the starts, `heuristic` must not overestimate the remaining cost to a goal
+pub fn astar<C, I>(
+    starts: impl IntoIterator<Item = C>,
+    successors: impl FnMut(&C) -> I,
+    heuristic: impl FnMut(&C) -> usize,
+    is_goal: impl FnMut(&C) -> bool,
+) -> PathResult<C>
+where
+    C: Clone + Eq + Hash,
+    I: IntoIterator<Item = (C, usize)>,
+{
+    let (goal, visited) = search(starts, successors, heuristic, is_goal);
+    goal.map(|index| visited.path(index))
+}
+
+/// Best first search, returns the index of the reached goal and all visited nodes
+fn search<C, I>(
+    starts: impl IntoIterator<Item = C>,
+    mut successors: impl FnMut(&C) -> I,
+    mut heuristic: impl FnMut(&C) -> usize,
+    mut is_goal: impl FnMut(&C) -> bool,
+) -> (Option<usize>, Visited<C>)
+where
+    C: Clone + Eq + Hash,
+    I: IntoIterator<Item = (C, usize)>,
+{
+    let mut visited = Visited::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(index) = visited.improve(start, 0, None) {
+            heap.push(Reverse((estimate, Reverse(0), index)));
+        }
+    }
+    // equal estimates: the deeper node (higher cost) first, it is probably closer to a goal
+    while let Some(Reverse((_, Reverse(cost), index))) = heap.pop() {
+        let (node, node_cost, _) = visited.get(index);
+        if cost > node_cost {
+            // outdated entry, the node was reached cheaper in the meantime
+            continue;
+        }
+        if is_goal(node) {
+            return (Some(index), visited);
+        }
+        for (successor, step_cost) in successors(node) {
+            let successor_cost = cost + step_cost;
+            let estimate = successor_cost + heuristic(&successor);
+            if let Some(successor_index) = visited.improve(successor, successor_cost, Some(index)) {
+                heap.push(Reverse((
+                    estimate,
+                    Reverse(successor_cost),
+                    successor_index,
+                )));
+            }
+        }
+    }
+    (None, visited)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_array::GridArray;
+    use crate::grid_hashmap::GridHashMap;
+    use crate::grid_trait::Grid;
+    use crate::grid_types::{Coor2D, Coor2DMut, Neighborhood, Topology};
+
+    const MAZE: &str = "S.#.\n.##.\n...E\n";
+    const COSTS: &str = "131\n191\n111\n";
+
+    fn no_wall<C>(_: (&C, &char), (_, to): (&C, &char)) -> bool {
+        *to != '#'
+    }
+
+    fn digit_cost<C>(_: (&C, &char), (_, to): (&C, &char)) -> Option<usize> {
+        to.to_digit(10).map(|digit| digit as usize)
+    }
+
+    #[test]
+    fn bfs_array() {
+        let grid = GridArray::from_newline_separated_string(
+            Topology::Bounded,
+            Neighborhood::Orthogonal,
+            MAZE,
+        );
+        let (path, steps) = grid
+            .bfs(&Coor2D::new(0, 0), |(_, cell)| *cell == 'E', no_wall)
+            .unwrap();
+        assert_eq!(steps, 5);
+        assert_eq!(path.len(), 6);
+        assert_eq!(path.last(), Some(&Coor2D::new(3, 2)));
+        assert!(grid
+            .bfs(&Coor2D::new(0, 0), |(_, cell)| *cell == 'E', |_, _| false)
+            .is_none());
+
+        let torus = GridArray::from_newline_separated_string(
+            Topology::Torus,
+            Neighborhood::Orthogonal,
+            MAZE,
+        );
+        let (_, steps) = torus
+            .bfs(&Coor2D::new(0, 0), |(_, cell)| *cell == 'E', no_wall)
+            .unwrap();
+        // north wraps to the bottom row, west to the last column
+        assert_eq!(steps, 2);
+
+        let (path, steps) = grid
+            .bfs_multi(
+                [Coor2D::new(0, 0), Coor2D::new(3, 0)],
+                |(_, cell)| *cell == 'E',
+                no_wall,
+            )
+            .unwrap();
+        assert_eq!((path[0].clone(), steps), (Coor2D::new(3, 0), 2));
+
+        let distances = grid.bfs_all([Coor2D::new(0, 0)], no_wall);
+        assert_eq!(distances.len(), 9);
+        assert_eq!(distances[&Coor2D::new(3, 0)], 7);
+    }
+
+    #[test]
+    fn dijkstra_array() {
+        let grid = GridArray::from_newline_separated_string(
+            Topology::Bounded,
+            Neighborhood::Orthogonal,
+            COSTS,
+        );
+        let goal = Coor2D::new(2, 2);
+        let is_goal = |(coor, _): (&Coor2D, &char)| *coor == goal;
+        let (path, cost) = grid
+            .dijkstra(&Coor2D::new(0, 0), is_goal, digit_cost)
+            .unwrap();
+        assert_eq!(cost, 4);
+        assert_eq!(path[1], Coor2D::new(0, 1));
+        let (_, cost) = grid
+            .astar(&Coor2D::new(0, 0), is_goal, digit_cost, |coor| {
+                coor.manhattan_distance(&goal)
+            })
+            .unwrap();
+        assert_eq!(cost, 4);
+        let (_, cost) = grid
+            .dijkstra_multi([Coor2D::new(0, 0), Coor2D::new(2, 0)], is_goal, digit_cost)
+            .unwrap();
+        assert_eq!(cost, 2);
+
+        let costs = grid.dijkstra_all([Coor2D::new(0, 0)], digit_cost);
+        assert_eq!(costs[&Coor2D::new(0, 0)], 0);
+        assert_eq!(costs[&Coor2D::new(1, 1)], 10);
+        assert_eq!(costs[&Coor2D::new(2, 0)], 4);
+    }
+
+    #[test]
+    fn hashmap() {
+        let mut grid = GridHashMap::from_newline_separated_string(Neighborhood::Orthogonal, MAZE);
+        let walls = grid
+            .all_cells()
+            .filter(|(_, cell)| *cell == Some(&'#'))
+            .map(|(coor, _)| coor)
+            .collect::<Vec<_>>();
+        for wall in &walls {
+            grid.remove(wall);
+        }
+        let (path, steps) = grid
+            .bfs(&Coor2DMut::new(0, 0), |(_, cell)| *cell == 'E', |_, _| true)
+            .unwrap();
+        assert_eq!((path.len(), steps), (6, 5));
+        assert!(grid
+            .bfs(&Coor2DMut::new(2, 0), |(_, cell)| *cell == 'E', |_, _| true)
+            .is_none());
+        assert_eq!(grid.bfs_all([Coor2DMut::new(0, 0)], |_, _| true).len(), 9);
+
+        let grid = GridHashMap::from_newline_separated_string(Neighborhood::Orthogonal, COSTS);
+        let goal = Coor2DMut::new(2, 2);
+        let (_, cost) = grid
+            .dijkstra(&Coor2DMut::new(0, 0), |(coor, _)| *coor == goal, digit_cost)
+            .unwrap();
+        assert_eq!(cost, 4);
+        assert_eq!(
+            grid.dijkstra_all([Coor2DMut::new(0, 0)], digit_cost)[&Coor2DMut::new(1, 1)],
+            10
+        );
+    }
+
+    #[test]
+    fn generic() {
+        // numbers reachable by +3 or *2, cost of a step is the new number
+        let successors = |n: &usize| {
+            [(n + 3, n + 3), (n * 2, n * 2)]
+                .into_iter()
+                .filter(|(n, _)| *n <= 20)
+        };
+        let (path, cost) = dijkstra([1], successors, |n| *n == 8).unwrap();
+        assert_eq!((path, cost), (vec![1, 4, 8], 12));
+        let (path, steps) = bfs([1], |n| successors(n).map(|(n, _)| n), |n| *n == 10).unwrap();
+        assert_eq!((path, steps), (vec![1, 4, 7, 10], 3));
+    }
+}
@@ -0,0 +1,256 @@
+//! Flood fill and connected components (regions of neighboring cells) with their sizes and
+//! bounding boxes, the grids offer them with their topology and neighborhood
+
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::hash::Hash;
+
+use fxhash::{FxHashMap, FxHashSet};
+
+use crate::grid_types::{Coor2DMut, Coor3DMut};
+
+/// Connected region of cells
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Component<C> {
+    /// id of the component (index in [`Components`])
+    pub id: usize,
+    /// cells in the order they were reached
+    pub cells: Vec<C>,
+    /// smallest coordinates of the bounding box
+    pub min: C,
+    /// largest coordinates of the bounding box
+    pub max: C,
+}
+
+impl<C> Component<C> {
+    /// number of cells
+    pub fn size(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+impl<C: MinMax> Component<C> {
+    /// Component of given (not empty) cells, calculates the bounding box
+    pub fn new(id: usize, cells: Vec<C>) -> Self {
+        let (min, max) = cells[1..]
+            .iter()
+            .fold((cells[0].clone(), cells[0].clone()), |(min, max), coor| {
+                (min.min_coor(coor), max.max_coor(coor))
+            });
+        Component {
+            id,
+            cells,
+            min,
+            max,
+        }
+    }
+}
+
+/// Coordinates with a minimum and maximum per axis (corners of bounding boxes)
+pub trait MinMax: Clone {
+    /// minimum per axis
+    #[must_use]
+    fn min_coor(&self, other: &Self) -> Self;
+    /// maximum per axis
+    #[must_use]
+    fn max_coor(&self, other: &Self) -> Self;
+}
+
+impl<T: Clone + Ord + Eq + Display> MinMax for Coor2DMut<T> {
+    fn min_coor(&self, other: &Self) -> Self {
+        self.min(other)
+    }
+
+    fn max_coor(&self, other: &Self) -> Self {
+        self.max(other)
+    }
+}
+
+impl<T: Clone + Ord + Eq + Display> MinMax for Coor3DMut<T> {
+    fn min_coor(&self, other: &Self) -> Self {
+        self.min(other)
+    }
+
+    fn max_coor(&self, other: &Self) -> Self {
+        self.max(other)
+    }
+}
+
+/// All components of a grid with the component id of every cell
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components<C: Eq + Hash> {
+    /// components ordered by id
+    pub components: Vec<Component<C>>,
+    ids: FxHashMap<C, usize>,
+}
+
+impl<C: Clone + Eq + Hash> Components<C> {
+    /// Labels the cells of the components (the ids must be their index)
+    pub fn new(components: Vec<Component<C>>) -> Self {
+        let ids = components
+            .iter()
+            .flat_map(|component| {
+                debug_assert_eq!(components[component.id].id, component.id);
+                component
+                    .cells
+                    .iter()
+                    .map(|cell| (cell.clone(), component.id))
+            })
+            .collect();
+        Components { components, ids }
+    }
+
+    /// number of components
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    /// no cell matched
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// id of the component containing the cell (None if the cell is in no component)
+    pub fn id(&self, coor: &C) -> Option<usize> {
+        self.ids.get(coor).copied()
+    }
+
+    /// component containing the cell
+    pub fn component(&self, coor: &C) -> Option<&Component<C>> {
+        self.id(coor).map(|id| &self.components[id])
+    }
+
+    /// sizes of the components ordered by id
+    pub fn sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.components.iter().map(Component::size)
+    }
+}
+
+/// All nodes reachable from `start` (included, in the order they are reached)
+pub fn flood_fill<C, I>(start: C, neighbors: impl FnMut(&C) -> I) -> Vec<C>
+where
+    C: Clone + Eq + Hash,
+    I: IntoIterator<Item = C>,
+{
+    fill(start, &mut FxHashSet::default(), neighbors)
+}
+
+/// Groups the nodes into sets of nodes reachable from each other,
+/// `neighbors` must be symmetric and only return nodes of `nodes`
+pub fn connected_components<C, I>(
+    nodes: impl IntoIterator<Item = C>,
+    mut neighbors: impl FnMut(&C) -> I,
+) -> Vec<Vec<C>>
+where
+    C: Clone + Eq + Hash,
+    I: IntoIterator<Item = C>,
+{
+    let mut visited = FxHashSet::default();
+    let mut components = Vec::new();
+    for node in nodes {
+        if !visited.contains(&node) {
+            components.push(fill(node, &mut visited, &mut neighbors));
+        }
+    }
+    components
+}
+
+fn fill<C, I>(start: C, visited: &mut FxHashSet<C>, mut neighbors: impl FnMut(&C) -> I) -> Vec<C>
+where
+    C: Clone + Eq + Hash,
+    I: IntoIterator<Item = C>,
+{
+    visited.insert(start.clone());
+    let mut cells = Vec::new();
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        for neighbor in neighbors(&node) {
+            if visited.insert(neighbor.clone()) {
+                queue.push_back(neighbor);
+            }
+        }
+        cells.push(node);
+    }
+    cells
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_array::GridArray;
+    use crate::grid_hashmap::GridHashMap;
+    use crate::grid_trait::Grid;
+    use crate::grid_types::{Coor2D, Neighborhood, Topology};
+
+    const ISLANDS: &str = "##..#\n#...#\n..#..\n....#\n";
+
+    #[test]
+    fn array() {
+        let grid = GridArray::from_newline_separated_string(
+            Topology::Bounded,
+            Neighborhood::Orthogonal,
+            ISLANDS,
+        );
+        let components = grid.connected_components(|(_, cell)| *cell == '#');
+        assert_eq!(components.len(), 4);
+        assert_eq!(components.sizes().collect::<Vec<_>>(), [3, 2, 1, 1]);
+        let first = &components.components[0];
+        assert_eq!(
+            (first.min.clone(), first.max.clone()),
+            (Coor2D::new(0, 0), Coor2D::new(1, 1))
+        );
+        assert_eq!(components.id(&Coor2D::new(4, 1)), Some(1));
+        assert_eq!(components.component(&Coor2D::new(4, 0)).unwrap().size(), 2);
+        assert_eq!(components.id(&Coor2D::new(3, 3)), None);
+
+        let air = grid
+            .flood_fill(&Coor2D::new(2, 0), |(_, cell)| *cell == '.')
+            .unwrap();
+        assert_eq!(air.size(), 13);
+        assert_eq!(air.cells[0], Coor2D::new(2, 0));
+        assert!(grid
+            .flood_fill(&Coor2D::new(0, 0), |(_, cell)| *cell == '.')
+            .is_none());
+
+        // on a torus the islands at the edges are connected (also diagonally over the corners)
+        let torus = GridArray::from_newline_separated_string(
+            Topology::Torus,
+            Neighborhood::Square,
+            ISLANDS,
+        );
+        let components = torus.connected_components(|(_, cell)| *cell == '#');
+        assert_eq!(components.sizes().collect::<Vec<_>>(), [6, 1]);
+        assert_eq!(components.components[0].max, Coor2D::new(4, 3));
+    }
+
+    #[test]
+    fn hashmap() {
+        let mut grid =
+            GridHashMap::from_newline_separated_string(Neighborhood::Orthogonal, ISLANDS);
+        grid.remove(&Coor2DMut::new(3, 2));
+        // (4, 2) is cut off by the removed cell
+        let components = grid.connected_components(|(_, cell)| *cell == '.');
+        assert_eq!(components.sizes().collect::<Vec<_>>(), [11, 1]);
+        let rocks = grid.connected_components(|(_, cell)| *cell == '#');
+        assert_eq!(rocks.sizes().collect::<Vec<_>>(), [3, 2, 1, 1]);
+        assert_eq!(rocks.components[2].min, Coor2DMut::new(2, 2));
+
+        let air = grid
+            .flood_fill(&Coor2DMut::new(4, 2), |(_, cell)| *cell == '.')
+            .unwrap();
+        assert_eq!(air.size(), 1);
+        assert!(grid.flood_fill(&Coor2DMut::new(3, 2), |_| true).is_none());
+    }
+
+    #[test]
+    fn generic() {
+        let components = connected_components(0..10, |n: &i32| {
+            [n - 3, n + 3].into_iter().filter(|n| (0..10).contains(n))
+        });
+        assert_eq!(components, [vec![0, 3, 6, 9], vec![1, 4, 7], vec![2, 5, 8]]);
+        assert_eq!(
+            flood_fill(1, |n: &i32| (*n < 4).then_some(n * 2)),
+            [1, 2, 4]
+        );
+    }
+}
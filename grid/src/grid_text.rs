@@ -0,0 +1,315 @@
+//! Text formats of grids: one cell per char mapped by a function (`GridArray::parse_with`,
+//! `GridHashMap::parse_with` and their `FromStr` impls) and a run-length format for sparse hash
+//! map grids (`GridHashMap::to_run_length_string`, `GridHashMap::from_run_length_str`)
+//!
+//! The run-length format has one line per horizontal segment of consecutive cells: `x,y:` of the
+//! first cell followed by runs `<count><char>` of equal cells, e.g. `-1,0:3#1o` are the cells
+//! (-1,0) to (1,0) mapped to '#' and the cell (2,0) mapped to 'o'
+//!
+//! With the feature `serde` the grids and their types implement `Serialize` and `Deserialize`
+//! (the cells of the hash map grids as a sequence of (coor, cell), journals are not serialized)
+
+use std::fmt::{Display, Write};
+
+use itertools::Itertools;
+
+use crate::grid_types::Coor2DMut;
+
+/// Error while parsing a grid (line and column start at 1, the column counts chars)
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseGridError {
+    /// line of the error
+    pub line: usize,
+    /// column of the error
+    pub column: usize,
+    /// text that could not be parsed
+    pub text: String,
+    /// what is wrong with the text
+    pub message: String,
+}
+
+impl ParseGridError {
+    pub(crate) fn new(
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        message: impl Display,
+    ) -> Self {
+        ParseGridError {
+            line,
+            column,
+            text: text.into(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}: {} ({:?})",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseGridError {}
+
+/// Rows of the cells of the lines of input, every char mapped by map
+pub(crate) fn parse_rows<T, E: Display>(
+    input: &str,
+    mut map: impl FnMut(char) -> Result<T, E>,
+) -> Result<Vec<Vec<T>>, ParseGridError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(x, ch)| map(ch).map_err(|err| ParseGridError::new(y + 1, x + 1, ch, err)))
+                .collect()
+        })
+        .collect()
+}
+
+/// Run-length lines of the cells (sorted by y, then x) mapped to chars
+pub(crate) fn to_run_length_string(cells: &[(Coor2DMut<isize>, char)]) -> String {
+    let mut result = String::new();
+    let segments = cells.iter().enumerate().group_by(|(index, (coor, _))| {
+        // consecutive cells of a row have the same difference of index and x
+        (coor.y, coor.x - isize::try_from(*index).unwrap())
+    });
+    for (_, segment) in &segments {
+        let mut segment = segment.map(|(_, cell)| cell).peekable();
+        let start = &segment.peek().unwrap().0;
+        write!(result, "{},{}:", start.x, start.y).unwrap();
+        for (count, ch) in segment.map(|(_, ch)| *ch).dedup_with_count() {
+            assert!(
+                !ch.is_ascii_digit() && ch != '\n' && ch != '\r',
+                "{ch:?} can not be stored as a run"
+            );
+            write!(result, "{count}{ch}").unwrap();
+        }
+        result.push('\n');
+    }
+    result
+}
+
+/// Cells of run-length lines, the chars mapped by map
+pub(crate) fn parse_run_length<T: Clone, E: Display>(
+    input: &str,
+    mut map: impl FnMut(char) -> Result<T, E>,
+) -> Result<Vec<(Coor2DMut<isize>, T)>, ParseGridError> {
+    let mut cells = Vec::new();
+    for (line_index, line) in input.lines().enumerate() {
+        let error = |column: usize, text: &str, message: &str| {
+            ParseGridError::new(line_index + 1, column + 1, text, message)
+        };
+        if line.is_empty() {
+            continue;
+        }
+        let (start, runs) = line
+            .split_once(':')
+            .ok_or_else(|| error(0, line, "expected x,y:runs"))?;
+        let coor = start
+            .split_once(',')
+            .and_then(|(x, y)| Some(Coor2DMut::new(x.parse().ok()?, y.parse().ok()?)))
+            .ok_or_else(|| error(0, start, "expected x,y"))?;
+
+        let mut x = coor.x;
+        let mut count = String::new();
+        for (column, ch) in runs.chars().enumerate() {
+            let column = start.len() + 1 + column;
+            if ch.is_ascii_digit() {
+                count.push(ch);
+                continue;
+            }
+            let run_length: isize = match count.parse() {
+                Ok(run_length) if run_length > 0 => run_length,
+                _ => return Err(error(column, &count, "expected a count > 0")),
+            };
+            let cell =
+                map(ch).map_err(|err| ParseGridError::new(line_index + 1, column + 1, ch, err))?;
+            cells.extend((x..x + run_length).map(|x| (Coor2DMut::new(x, coor.y), cell.clone())));
+            x += run_length;
+            count.clear();
+        }
+        if !count.is_empty() {
+            return Err(error(line.len(), &count, "run without a cell"));
+        }
+    }
+    Ok(cells)
+}
+
+/// (De)serializes the cells of a hash map grid as a sequence of (coor, cell), formats like JSON
+/// only allow strings as keys of maps
+#[cfg(feature = "serde")]
+pub(crate) mod serde_cells {
+    use std::hash::Hash;
+
+    use fxhash::FxHashMap;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(crate) fn serialize<C: Serialize, T: Serialize, S: Serializer>(
+        cells: &FxHashMap<C, T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(cells)
+    }
+
+    pub(crate) fn deserialize<'de, C, T, D>(deserializer: D) -> Result<FxHashMap<C, T>, D::Error>
+    where
+        C: Deserialize<'de> + Eq + Hash,
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Ok(Vec::<(C, T)>::deserialize(deserializer)?
+            .into_iter()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_array::GridArray;
+    use crate::grid_hashmap::{GridHashMap, GridHashMapBuilder};
+    use crate::grid_types::{Neighborhood, Topology};
+
+    #[test]
+    fn parse_with() {
+        let grid: GridArray<u8> = "303\n255\n".parse().unwrap();
+        assert_eq!(grid.get(2, 1), Some(&5));
+        assert_eq!(grid.to_string(), "303\n255\n");
+        assert_eq!(
+            "30\n2x\n".parse::<GridArray<u8>>(),
+            Err(ParseGridError::new(
+                2,
+                2,
+                "x",
+                "invalid digit found in string"
+            ))
+        );
+        assert_eq!(
+            "303\n25\n"
+                .parse::<GridArray<u8>>()
+                .unwrap_err()
+                .to_string(),
+            "2:3: 2 cells, expected 3 (\"25\")"
+        );
+
+        let grid = GridArray::parse_with(
+            Topology::Torus,
+            Neighborhood::Orthogonal,
+            "#.\n.#",
+            |ch| match ch {
+                '#' => Ok(true),
+                '.' => Ok(false),
+                _ => Err("expected '#' or '.'"),
+            },
+        )
+        .unwrap();
+        assert_eq!(grid.get_topology(), Topology::Torus);
+        assert_eq!(grid.to_string(), "truefalse\nfalsetrue\n");
+
+        let elves = GridHashMap::parse_with(Neighborhood::Square, "..#\n#.\n", |ch| match ch {
+            '#' => Ok(Some(true)),
+            '.' => Ok(None),
+            _ => Err("expected '#' or '.'"),
+        })
+        .unwrap();
+        assert_eq!(
+            elves
+                .all_indexes()
+                .sorted_by_key(|coor| (coor.y, coor.x))
+                .collect_vec(),
+            vec![Coor2DMut::new(2, 0), Coor2DMut::new(0, 1)]
+        );
+        let grid: GridHashMap<char> = "ab\nc".parse().unwrap();
+        assert_eq!(grid.get(&Coor2DMut::new(0, 1)), Some(&'c'));
+    }
+
+    #[test]
+    fn run_length() {
+        let mut grid = GridHashMap::parse_with(Neighborhood::Square, "##o.o\n\n..##\n", |ch| {
+            Ok::<_, String>((ch != '.').then_some(ch))
+        })
+        .unwrap();
+        grid.set(Coor2DMut::new(-1, 0), '#');
+        let text = grid.to_run_length_string(|cell| *cell);
+        assert_eq!(text, "-1,0:3#1o\n4,0:1o\n2,2:2#\n");
+        let parsed =
+            GridHashMap::from_run_length_str(Neighborhood::Square, &text, Ok::<_, String>).unwrap();
+        assert_eq!(parsed, grid);
+        let empty: GridHashMap<char> = GridHashMapBuilder::default().build().unwrap();
+        assert_eq!(empty.to_run_length_string(|cell| *cell), "");
+
+        for (text, error) in [
+            ("1,2", "1:1: expected x,y:runs (\"1,2\")"),
+            ("1;2:1#", "1:1: expected x,y (\"1;2\")"),
+            ("\n1,2:1#0#", "2:8: expected a count > 0 (\"0\")"),
+            ("1,2:1#12", "1:9: run without a cell (\"12\")"),
+            ("1,2:#", "1:5: expected a count > 0 (\"\")"),
+            ("1,2:2x", "1:6: no x (\"x\")"),
+        ] {
+            assert_eq!(
+                GridHashMap::from_run_length_str(Neighborhood::Square, text, |ch| match ch {
+                    '#' => Ok(true),
+                    _ => Err(format!("no {ch}")),
+                })
+                .unwrap_err()
+                .to_string(),
+                error
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let grid: GridArray<u8> = "12\n34\n".parse().unwrap();
+        let json = serde_json::to_string(&grid).unwrap();
+        assert_eq!(
+            json,
+            r#"{"width":2,"height":2,"topology":"Bounded","neighborhood":"Square","data":[1,2,3,4]}"#
+        );
+        assert_eq!(serde_json::from_str::<GridArray<u8>>(&json).unwrap(), grid);
+        let too_few = json.replace("[1,2,3,4]", "[1,2,3]");
+        assert!(serde_json::from_str::<GridArray<u8>>(&too_few)
+            .unwrap_err()
+            .to_string()
+            .starts_with("3 cells, expected 2x2"));
+
+        let mut elves = GridHashMap::parse_with(
+            Neighborhood::Hex(crate::grid_types::HexLayout::OddRows),
+            "#.\n.#\n",
+            |ch| Ok::<_, String>((ch == '#').then_some(true)),
+        )
+        .unwrap();
+        elves.start_journal();
+        elves.set(Coor2DMut::new(-3, 4), true);
+        let json = serde_json::to_string(&elves).unwrap();
+        let parsed: GridHashMap<bool> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.get_journal(), None);
+        assert_eq!(parsed, elves);
+        let empty: GridHashMap<bool> = GridHashMapBuilder::default().build().unwrap();
+        assert_eq!(
+            serde_json::to_string(&empty).unwrap(),
+            r#"{"neighborhood":"Square","topology":"Bounded","bounds":null,"data":[]}"#
+        );
+
+        let cube = GridArray::from_newline_separated_string(
+            Topology::Bounded,
+            Neighborhood::Orthogonal,
+            "..#.\n####\n..#.\n",
+        )
+        .with_cube_net(|ch| *ch == '#')
+        .unwrap();
+        let json = serde_json::to_string(&cube).unwrap();
+        assert_eq!(
+            serde_json::from_str::<GridArray<char>>(&json).unwrap(),
+            cube
+        );
+    }
+}
@@ -0,0 +1,470 @@
+//! Common interface of the 2D grids ([`GridArray`] and [`GridHashMap`]), algorithms written
+//! against [`Grid`] work with both storages (e.g. the shortest paths of [`Grid::bfs`] or the regions of
+//! [`Grid::connected_components`])
+
+use std::fmt::Display;
+use std::hash::Hash;
+
+use fxhash::{FxHashMap, FxHashSet};
+
+use crate::grid_array::GridArray;
+use crate::grid_hashmap::GridHashMap;
+use crate::grid_pathfinding::{self, PathResult};
+use crate::grid_regions::{self, Component, Components, MinMax};
+use crate::grid_types::{Coor2D, Coor2DMut};
+
+/// 2D grid, cells are addressed by `Coor` (cells of a hash map grid may be missing)
+pub trait Grid {
+    /// value of a cell
+    type Cell;
+    /// coordinate of a cell
+    type Coor: Clone + Eq + Hash + Display;
+
+    /// get reference to the cell on coor
+    fn cell(&self, coor: &Self::Coor) -> Option<&Self::Cell>;
+
+    /// get mutable reference to the cell on coor
+    fn cell_mut(&mut self, coor: &Self::Coor) -> Option<&mut Self::Cell>;
+
+    /// set new cell on coor and return the old cell (nothing is set if coor is outside of the grid)
+    fn set_cell(&mut self, coor: Self::Coor, new_value: Self::Cell) -> Option<Self::Cell>;
+
+    /// Is coor inside the grid (the cell may still be missing)
+    fn contains(&self, coor: &Self::Coor) -> bool;
+
+    /// min and max coor (both included): the size of an array, the bounds of a hash map or
+    /// (if it is unbounded) the bounding box of its cells, None if there is nothing to span (an
+    /// array without width or height, an unbounded hash map without cells)
+    fn min_max(&self) -> Option<(Self::Coor, Self::Coor)>;
+
+    /// all neighbor coors inside the grid (based on topology and neighborhood)
+    fn neighbors(&self, coor: &Self::Coor) -> impl Iterator<Item = Self::Coor>;
+
+    /// all existing cells
+    fn cells(&self) -> impl Iterator<Item = (Self::Coor, &Self::Cell)>;
+
+    /// x and y of coor
+    fn to_xy(coor: &Self::Coor) -> (isize, isize);
+
+    /// coor of x and y (None if the grid can not address it, e.g. negative for an array)
+    fn from_xy(x: isize, y: isize) -> Option<Self::Coor>;
+
+    /// all existing neighbor cells
+    fn neighbor_cells(&self, coor: &Self::Coor) -> impl Iterator<Item = (Self::Coor, &Self::Cell)> {
+        self.neighbors(coor)
+            .filter_map(|neighbor| Some((neighbor.clone(), self.cell(&neighbor)?)))
+    }
+
+    /// Rows (from min to max) of the mapped cells, missing cells are passed as None
+    fn to_string_with_mapping(
+        &self,
+        mut map_fn: impl FnMut(Option<&Self::Cell>) -> char,
+    ) -> String {
+        let Some((min, max)) = self.min_max() else {
+            return String::new();
+        };
+        let ((min_x, min_y), (max_x, max_y)) = (Self::to_xy(&min), Self::to_xy(&max));
+        let mut result = String::new();
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                result.push(map_fn(
+                    Self::from_xy(x, y).and_then(|coor| self.cell(&coor)),
+                ));
+            }
+            result.push('\n');
+        }
+        result
+    }
+
+    /// Print grid (see [`Grid::to_string_with_mapping`])
+    fn print_with(&self, map_fn: impl FnMut(Option<&Self::Cell>) -> char) {
+        print!("{}", self.to_string_with_mapping(map_fn));
+    }
+
+    // Shortest paths between cells, a step goes to an existing neighbor (based on topology and
+    // neighborhood), the closures get the cells as (coor, value), starts that are no cells are
+    // ignored
+
+    /// Path with the fewest steps from `start` to a cell matching `is_goal`,
+    /// a step is only possible if `can_move(from, to)`
+    fn bfs(
+        &self,
+        start: &Self::Coor,
+        is_goal: impl FnMut((&Self::Coor, &Self::Cell)) -> bool,
+        can_move: impl FnMut((&Self::Coor, &Self::Cell), (&Self::Coor, &Self::Cell)) -> bool,
+    ) -> PathResult<Self::Coor> {
+        self.bfs_multi([start.clone()], is_goal, can_move)
+    }
+
+    /// Like [`Grid::bfs`] but starting from the nearest of several cells
+    fn bfs_multi(
+        &self,
+        starts: impl IntoIterator<Item = Self::Coor>,
+        mut is_goal: impl FnMut((&Self::Coor, &Self::Cell)) -> bool,
+        mut can_move: impl FnMut((&Self::Coor, &Self::Cell), (&Self::Coor, &Self::Cell)) -> bool,
+    ) -> PathResult<Self::Coor> {
+        grid_pathfinding::bfs(
+            existing(self, starts),
+            |coor| moves(self, coor, &mut can_move),
+            |coor| is_goal((coor, existing_cell(self, coor))),
+        )
+    }
+
+    /// Number of steps from the nearest start to every reachable cell
+    fn bfs_all(
+        &self,
+        starts: impl IntoIterator<Item = Self::Coor>,
+        mut can_move: impl FnMut((&Self::Coor, &Self::Cell), (&Self::Coor, &Self::Cell)) -> bool,
+    ) -> FxHashMap<Self::Coor, usize> {
+        grid_pathfinding::bfs_all(existing(self, starts), |coor| {
+            moves(self, coor, &mut can_move)
+        })
+    }
+
+    /// Cheapest path from `start` to a cell matching `is_goal`,
+    /// `step_cost(from, to)` returns the cost of a step (None if it is not possible)
+    fn dijkstra(
+        &self,
+        start: &Self::Coor,
+        is_goal: impl FnMut((&Self::Coor, &Self::Cell)) -> bool,
+        step_cost: impl FnMut((&Self::Coor, &Self::Cell), (&Self::Coor, &Self::Cell)) -> Option<usize>,
+    ) -> PathResult<Self::Coor> {
+        self.dijkstra_multi([start.clone()], is_goal, step_cost)
+    }
+
+    /// Like [`Grid::dijkstra`] but starting from the cheapest of several cells
+    fn dijkstra_multi(
+        &self,
+        starts: impl IntoIterator<Item = Self::Coor>,
+        is_goal: impl FnMut((&Self::Coor, &Self::Cell)) -> bool,
+        step_cost: impl FnMut((&Self::Coor, &Self::Cell), (&Self::Coor, &Self::Cell)) -> Option<usize>,
+    ) -> PathResult<Self::Coor> {
+        self.astar_multi(starts, is_goal, step_cost, |_| 0)
+    }
+
+    /// Cost from the nearest start to every reachable cell
+    fn dijkstra_all(
+        &self,
+        starts: impl IntoIterator<Item = Self::Coor>,
+        mut step_cost: impl FnMut(
+            (&Self::Coor, &Self::Cell),
+            (&Self::Coor, &Self::Cell),
+        ) -> Option<usize>,
+    ) -> FxHashMap<Self::Coor, usize> {
+        grid_pathfinding::dijkstra_all(existing(self, starts), |coor| {
+            steps(self, coor, &mut step_cost)
+        })
+    }
+
+    /// Like [`Grid::dijkstra`] but guided by `heuristic` (must not overestimate the remaining
+    /// cost, e.g. the manhattan distance for orthogonal steps costing at least 1)
+    fn astar(
+        &self,
+        start: &Self::Coor,
+        is_goal: impl FnMut((&Self::Coor, &Self::Cell)) -> bool,
+        step_cost: impl FnMut((&Self::Coor, &Self::Cell), (&Self::Coor, &Self::Cell)) -> Option<usize>,
+        heuristic: impl FnMut(&Self::Coor) -> usize,
+    ) -> PathResult<Self::Coor> {
+        self.astar_multi([start.clone()], is_goal, step_cost, heuristic)
+    }
+
+    /// Like [`Grid::astar`] but starting from several cells
+    fn astar_multi(
+        &self,
+        starts: impl IntoIterator<Item = Self::Coor>,
+        mut is_goal: impl FnMut((&Self::Coor, &Self::Cell)) -> bool,
+        mut step_cost: impl FnMut(
+            (&Self::Coor, &Self::Cell),
+            (&Self::Coor, &Self::Cell),
+        ) -> Option<usize>,
+        heuristic: impl FnMut(&Self::Coor) -> usize,
+    ) -> PathResult<Self::Coor> {
+        grid_pathfinding::astar(
+            existing(self, starts),
+            |coor| steps(self, coor, &mut step_cost),
+            heuristic,
+            |coor| is_goal((coor, existing_cell(self, coor))),
+        )
+    }
+
+    // Regions of existing cells, two cells are connected if they are neighbors (based on topology
+    // and neighborhood), on a torus the bounding box of a wrapping region spans the grid
+
+    /// All existing cells matching `passable` that are reachable from `start`
+    /// (None if `start` does not exist or is not passable)
+    fn flood_fill(
+        &self,
+        start: &Self::Coor,
+        mut passable: impl FnMut((&Self::Coor, &Self::Cell)) -> bool,
+    ) -> Option<Component<Self::Coor>>
+    where
+        Self::Coor: MinMax,
+    {
+        if !passable((start, self.cell(start)?)) {
+            return None;
+        }
+        let cells = grid_regions::flood_fill(start.clone(), |coor| {
+            self.neighbor_cells(coor)
+                .filter(|(neighbor, cell)| passable((neighbor, cell)))
+                .map(|(neighbor, _)| neighbor)
+                .collect::<Vec<_>>()
+        });
+        Some(Component::new(0, cells))
+    }
+
+    /// Connected regions of the existing cells matching `predicate` (ids in reading order of
+    /// their first cell)
+    fn connected_components(
+        &self,
+        mut predicate: impl FnMut((&Self::Coor, &Self::Cell)) -> bool,
+    ) -> Components<Self::Coor>
+    where
+        Self::Coor: MinMax,
+    {
+        let mut matching = self
+            .cells()
+            .filter(|(coor, cell)| predicate((coor, cell)))
+            .map(|(coor, _)| coor)
+            .collect::<Vec<_>>();
+        // ids in reading order instead of the order of the storage
+        matching.sort_by_key(|coor| {
+            let (x, y) = Self::to_xy(coor);
+            (y, x)
+        });
+        let matching_set = matching.iter().cloned().collect::<FxHashSet<_>>();
+        let components = grid_regions::connected_components(matching, |coor| {
+            self.neighbors(coor)
+                .filter(|neighbor| matching_set.contains(neighbor))
+                .collect::<Vec<_>>()
+        });
+        Components::new(
+            components
+                .into_iter()
+                .enumerate()
+                .map(|(id, cells)| Component::new(id, cells))
+                .collect(),
+        )
+    }
+}
+
+/// Only starts that are cells of the grid
+fn existing<G: Grid + ?Sized>(grid: &G, starts: impl IntoIterator<Item = G::Coor>) -> Vec<G::Coor> {
+    starts
+        .into_iter()
+        .filter(|start| grid.cell(start).is_some())
+        .collect()
+}
+
+/// Cell of a coor that was checked to exist
+fn existing_cell<'a, G: Grid + ?Sized>(grid: &'a G, coor: &G::Coor) -> &'a G::Cell {
+    grid.cell(coor).expect("existing cell")
+}
+
+/// Existing neighbors of a cell with the cost of the step to them
+fn steps<G: Grid + ?Sized>(
+    grid: &G,
+    coor: &G::Coor,
+    step_cost: &mut impl FnMut((&G::Coor, &G::Cell), (&G::Coor, &G::Cell)) -> Option<usize>,
+) -> Vec<(G::Coor, usize)> {
+    let cell = existing_cell(grid, coor);
+    grid.neighbor_cells(coor)
+        .filter_map(|(neighbor, neighbor_cell)| {
+            let cost = step_cost((coor, cell), (&neighbor, neighbor_cell))?;
+            Some((neighbor, cost))
+        })
+        .collect()
+}
+
+/// Existing neighbors of a cell that can be moved to
+fn moves<G: Grid + ?Sized>(
+    grid: &G,
+    coor: &G::Coor,
+    can_move: &mut impl FnMut((&G::Coor, &G::Cell), (&G::Coor, &G::Cell)) -> bool,
+) -> Vec<G::Coor> {
+    steps(grid, coor, &mut |from, to| can_move(from, to).then_some(1))
+        .into_iter()
+        .map(|(neighbor, _)| neighbor)
+        .collect()
+}
+
+impl<T: Default + Clone + Display> Grid for GridArray<T> {
+    type Cell = T;
+    type Coor = Coor2D;
+
+    fn cell(&self, coor: &Coor2D) -> Option<&T> {
+        self.get(coor.x, coor.y)
+    }
+
+    fn cell_mut(&mut self, coor: &Coor2D) -> Option<&mut T> {
+        self.get_mut(coor.x, coor.y)
+    }
+
+    fn set_cell(&mut self, coor: Coor2D, new_value: T) -> Option<T> {
+        self.contains(&coor)
+            .then(|| self.set(coor.x, coor.y, new_value))
+    }
+
+    fn contains(&self, coor: &Coor2D) -> bool {
+        coor.x < self.get_width() && coor.y < self.get_height()
+    }
+
+    fn min_max(&self) -> Option<(Coor2D, Coor2D)> {
+        Some((
+            Coor2D::new(0, 0),
+            Coor2D::new(
+                self.get_width().checked_sub(1)?,
+                self.get_height().checked_sub(1)?,
+            ),
+        ))
+    }
+
+    fn neighbors(&self, coor: &Coor2D) -> impl Iterator<Item = Coor2D> {
+        self.neighborhood_cell_indexes(coor.x, coor.y)
+    }
+
+    fn cells(&self) -> impl Iterator<Item = (Coor2D, &T)> {
+        self.all_cells()
+    }
+
+    fn to_xy(coor: &Coor2D) -> (isize, isize) {
+        (coor.x.try_into().unwrap(), coor.y.try_into().unwrap())
+    }
+
+    fn from_xy(x: isize, y: isize) -> Option<Coor2D> {
+        Some(Coor2D::new(x.try_into().ok()?, y.try_into().ok()?))
+    }
+}
+
+impl<T: Default + Clone + Display> Grid for GridHashMap<T> {
+    type Cell = T;
+    type Coor = Coor2DMut<isize>;
+
+    fn cell(&self, coor: &Coor2DMut<isize>) -> Option<&T> {
+        self.get(coor)
+    }
+
+    fn cell_mut(&mut self, coor: &Coor2DMut<isize>) -> Option<&mut T> {
+        self.get_mut(coor)
+    }
+
+    fn set_cell(&mut self, coor: Coor2DMut<isize>, new_value: T) -> Option<T> {
+        if self.contains(&coor) {
+            self.set(coor, new_value)
+        } else {
+            None
+        }
+    }
+
+    fn contains(&self, coor: &Coor2DMut<isize>) -> bool {
+        GridHashMap::contains(self, coor)
+    }
+
+    fn min_max(&self) -> Option<(Coor2DMut<isize>, Coor2DMut<isize>)> {
+        match self.get_bounds() {
+            Some(bounds) => Some(bounds.clone()),
+            None => self.iter().next().is_some().then(|| self.get_min_max()),
+        }
+    }
+
+    fn neighbors(&self, coor: &Coor2DMut<isize>) -> impl Iterator<Item = Coor2DMut<isize>> {
+        self.neighborhood_cell_indexes(coor)
+    }
+
+    fn cells(&self) -> impl Iterator<Item = (Coor2DMut<isize>, &T)> {
+        self.all_cells()
+            .filter_map(|(coor, cell)| Some((coor, cell?)))
+    }
+
+    fn to_xy(coor: &Coor2DMut<isize>) -> (isize, isize) {
+        coor.to_tuple()
+    }
+
+    fn from_xy(x: isize, y: isize) -> Option<Coor2DMut<isize>> {
+        Some(Coor2DMut::new(x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_array::GridArrayBuilder;
+    use crate::grid_types::{Neighborhood, Topology};
+
+    /// number of '#' cells with at least two '#' neighbors, written once for both grids
+    fn crowded<G: Grid<Cell = char>>(grid: &G) -> usize {
+        grid.cells()
+            .filter(|(coor, cell)| {
+                **cell == '#'
+                    && grid
+                        .neighbor_cells(coor)
+                        .filter(|(_, neighbor)| **neighbor == '#')
+                        .count()
+                        >= 2
+            })
+            .count()
+    }
+
+    fn change<G: Grid<Cell = char>>(grid: &mut G, x: isize, y: isize) -> Option<char> {
+        grid.set_cell(G::from_xy(x, y)?, '#')
+    }
+
+    #[test]
+    fn both_grids() {
+        let input = "#.#\n##.\n..#\n";
+        let mut array = GridArray::from_newline_separated_string(
+            Topology::Bounded,
+            Neighborhood::Square,
+            input,
+        );
+        let mut hashmap = GridHashMap::from_newline_separated_string(Neighborhood::Square, input);
+        hashmap.remove(&Coor2DMut::new(1, 0));
+
+        assert_eq!(crowded(&array), 3);
+        assert_eq!(crowded(&hashmap), 3);
+        assert_eq!(array.to_string_with_mapping(|cell| *cell.unwrap()), input);
+        assert_eq!(
+            hashmap.to_string_with_mapping(|cell| cell.copied().unwrap_or(' ')),
+            "# #\n##.\n..#\n"
+        );
+
+        assert_eq!(change(&mut array, 2, 1), Some('.'));
+        assert_eq!(change(&mut array, -1, 1), None);
+        assert_eq!(change(&mut array, 3, 1), None);
+        assert_eq!(
+            array.min_max(),
+            Some((Coor2D::new(0, 0), Coor2D::new(2, 2)))
+        );
+        assert_eq!(crowded(&array), 6);
+
+        assert_eq!(change(&mut hashmap, 1, 0), None);
+        assert_eq!(hashmap.cell(&Coor2DMut::new(1, 0)), Some(&'#'));
+        let mut bounded = hashmap.with_bounds(
+            (Coor2DMut::new(0, 0), Coor2DMut::new(3, 2)),
+            Topology::Bounded,
+        );
+        assert_eq!(change(&mut bounded, 4, 0), None);
+        assert_eq!(bounded.cells().count(), 9);
+        assert_eq!(Grid::min_max(&bounded).unwrap().1, Coor2DMut::new(3, 2));
+    }
+
+    #[test]
+    fn empty_grids() {
+        for (width, height) in [(0, 0), (0, 3), (3, 0)] {
+            let array: GridArray<char> = GridArrayBuilder::default()
+                .width(width)
+                .height(height)
+                .build()
+                .unwrap();
+            assert_eq!(array.min_max(), None);
+            assert_eq!(array.to_string_with_mapping(|_| '.'), "");
+        }
+        let mut hashmap = GridHashMap::<char>::from_1d_vec(Neighborhood::Square, 1, vec![]);
+        assert_eq!(Grid::min_max(&hashmap), None);
+        assert_eq!(hashmap.to_string_with_mapping(|_| '.'), "");
+        hashmap = hashmap.with_bounds(
+            (Coor2DMut::new(0, 0), Coor2DMut::new(1, 0)),
+            Topology::Bounded,
+        );
+        assert_eq!(hashmap.to_string_with_mapping(|_| '.'), "..\n");
+    }
+}
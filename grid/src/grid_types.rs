@@ -4,6 +4,8 @@ use std::fmt::Display;
 
 use derive_more::{Add, AddAssign, Constructor, Display, Sub, SubAssign};
 
+use crate::grid_cube::CubeNet;
+
 /// CellIndexCoorType
 pub type Coor2DIndex = usize;
 /// CellIndexType
@@ -30,6 +32,7 @@ pub type Coor2D = Coor2DMut<Coor2DIndex>;
 )]
 //#[into(owned, ref, ref_mut)]
 #[display(fmt = "({x},{y})")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Coor2DMut<T: Clone + Ord + Eq + Display> {
     /// x
     pub x: T,
@@ -101,17 +104,125 @@ impl<T: Clone + Ord + Eq + Display> Coor2DMut<T> {
     }
 }
 
+/// Coor index type of 3D grids
+pub type Coor3DIndex = usize;
+/// Cell index type of 3D grids
+pub type Coor3D = Coor3DMut<Coor3DIndex>;
+
+/// Coor in 3D
+#[derive(
+    Eq,
+    PartialEq,
+    Hash,
+    PartialOrd,
+    Clone,
+    Debug,
+    Add,
+    Sub,
+    AddAssign,
+    SubAssign,
+    Constructor,
+    Display,
+)]
+#[display(fmt = "({x},{y},{z})")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Coor3DMut<T: Clone + Ord + Eq + Display> {
+    /// x
+    pub x: T,
+    /// y
+    pub y: T,
+    /// z
+    pub z: T,
+}
+
+impl<T: Clone + Ord + Eq + Display> From<(T, T, T)> for Coor3DMut<T> {
+    fn from(t: (T, T, T)) -> Self {
+        Coor3DMut {
+            x: t.0,
+            y: t.1,
+            z: t.2,
+        }
+    }
+}
+
+impl<T: Clone + Ord + Eq + Display> Coor3DMut<T> {
+    /// to tuples
+    pub fn to_tuple(&self) -> (T, T, T) {
+        (self.x.clone(), self.y.clone(), self.z.clone())
+    }
+    /// from tuples
+    #[must_use]
+    pub fn from_tuple(t: (T, T, T)) -> Self {
+        Self::new(t.0, t.1, t.2)
+    }
+
+    /// to array
+    pub fn to_array(&self) -> [T; 3] {
+        [self.x.clone(), self.y.clone(), self.z.clone()]
+    }
+
+    /// min
+    #[must_use]
+    pub fn min(&self, other: &Self) -> Self {
+        Self::new(
+            self.x.clone().min(other.x.clone()),
+            self.y.clone().min(other.y.clone()),
+            self.z.clone().min(other.z.clone()),
+        )
+    }
+
+    /// max
+    #[must_use]
+    pub fn max(&self, other: &Self) -> Self {
+        Self::new(
+            self.x.clone().max(other.x.clone()),
+            self.y.clone().max(other.y.clone()),
+            self.z.clone().max(other.z.clone()),
+        )
+    }
+
+    /// Returns abs
+    pub fn abs(&self) -> usize
+    where
+        T: TryInto<isize>,
+        <T as TryInto<isize>>::Error: std::fmt::Debug,
+    {
+        #![allow(clippy::cast_sign_loss)]
+        (self.x.clone().try_into().unwrap().abs()
+            + self.y.clone().try_into().unwrap().abs()
+            + self.z.clone().try_into().unwrap().abs()) as usize
+    }
+
+    /// Returns manhattan distance
+    pub fn manhattan_distance(&self, other: &Coor3DMut<T>) -> usize
+    where
+        T: TryInto<isize>,
+        <T as TryInto<isize>>::Error: std::fmt::Debug,
+    {
+        #![allow(clippy::cast_sign_loss)]
+        ((self.x.clone().try_into().unwrap() - other.x.clone().try_into().unwrap()).abs()
+            + (self.y.clone().try_into().unwrap() - other.y.clone().try_into().unwrap()).abs()
+            + (self.z.clone().try_into().unwrap() - other.z.clone().try_into().unwrap()).abs())
+            as usize
+    }
+}
+
 /// A type of topology
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Topology {
     /// A bounded grid, with no wrap-around
-    Bounded = 0,
+    Bounded,
     /// A grid that wraps around, preserving the axis not moved in. e.g. Pacman
-    Torus = 1,
+    Torus,
+    /// The faces of a net folded onto a cube, stepping over an edge leads to the adjacent face
+    /// (e.g. `GridArray::with_cube_net`)
+    CubeNet(CubeNet),
 }
 
 /// All eight directions (Orthogonal+Diagonal)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Direction {
     /// North
@@ -168,6 +279,7 @@ impl Direction {
 
 /// Neighborhoods around a point. They do not contain the point itself
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Neighborhood {
     /// The neighborhood consisting of the points directly North, South, East, and West of a point.
     Orthogonal,
@@ -175,4 +287,120 @@ pub enum Neighborhood {
     Diagonal,
     /// The neighborhood consisting of the square directly around the point.
     Square,
+    /// The six points sharing an edge with a hexagon (the coors are stored as in the layout)
+    Hex(HexLayout),
+    /// The eight points a chess knight can jump to.
+    Knight,
+    /// User defined difference vectors (x, y) to the neighbors, e.g. `&[(0, 2), (0, -2)]` (can
+    /// not be deserialized)
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    Offsets(&'static [(isize, isize)]),
+}
+
+/// How the coors of a grid of pointy topped hexagons are stored (x is the column, y the row)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HexLayout {
+    /// Axial coors: going SouthEast increases y, going East increases x
+    Axial,
+    /// Offset coors: the odd rows are shifted half a hexagon to the East
+    OddRows,
+    /// Offset coors: the even rows are shifted half a hexagon to the East
+    EvenRows,
+}
+
+impl HexLayout {
+    /// Returns the axial coor of a coor stored in this layout
+    pub fn to_axial(&self, coor: &Coor2DMut<isize>) -> Coor2DMut<isize> {
+        match self {
+            HexLayout::Axial => coor.clone(),
+            HexLayout::OddRows => Coor2DMut::new(coor.x - coor.y.div_euclid(2), coor.y),
+            HexLayout::EvenRows => Coor2DMut::new(coor.x - (coor.y + 1).div_euclid(2), coor.y),
+        }
+    }
+
+    /// Returns the number of steps between two hexagons
+    pub fn distance(&self, coor: &Coor2DMut<isize>, other: &Coor2DMut<isize>) -> usize {
+        let diff = self.to_axial(coor) - self.to_axial(other);
+        (diff.x.unsigned_abs() + diff.y.unsigned_abs() + (diff.x + diff.y).unsigned_abs()) / 2
+    }
+}
+
+/// The six directions of a pointy topped hexagon (in clockwise order)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum HexDirection {
+    /// East
+    East = 0,
+    /// SouthEast
+    SouthEast = 1,
+    /// SouthWest
+    SouthWest = 2,
+    /// West
+    West = 3,
+    /// NorthWest
+    NorthWest = 4,
+    /// NorthEast
+    NorthEast = 5,
+}
+
+impl HexDirection {
+    /// All six directions (clockwise, starting East)
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::East,
+        HexDirection::SouthEast,
+        HexDirection::SouthWest,
+        HexDirection::West,
+        HexDirection::NorthWest,
+        HexDirection::NorthEast,
+    ];
+
+    /// Returns the direction rotated by the given number of degrees (multiple of 60, clockwise)
+    #[must_use]
+    pub fn rotate(&self, rotation: isize) -> Self {
+        let new_dir = (*self as isize + rotation / 60).rem_euclid(6);
+        HexDirection::ALL[usize::try_from(new_dir).unwrap()]
+    }
+
+    /// Returns the difference vector for direction from a hexagon in row y (the offset layouts
+    /// depend on the row)
+    pub fn diff_coor(&self, layout: HexLayout, y: isize) -> Coor2DMut<isize> {
+        let shifted_row = match layout {
+            HexLayout::Axial => {
+                return match self {
+                    HexDirection::East => Coor2DMut::new(1, 0),
+                    HexDirection::SouthEast => Coor2DMut::new(0, 1),
+                    HexDirection::SouthWest => Coor2DMut::new(-1, 1),
+                    HexDirection::West => Coor2DMut::new(-1, 0),
+                    HexDirection::NorthWest => Coor2DMut::new(0, -1),
+                    HexDirection::NorthEast => Coor2DMut::new(1, -1),
+                }
+            }
+            HexLayout::OddRows => y & 1 == 1,
+            HexLayout::EvenRows => y & 1 == 0,
+        };
+        // x of the neighbors in the rows above and below (West and East of the hexagon)
+        let (west_x, east_x) = if shifted_row { (0, 1) } else { (-1, 0) };
+        match self {
+            HexDirection::East => Coor2DMut::new(1, 0),
+            HexDirection::SouthEast => Coor2DMut::new(east_x, 1),
+            HexDirection::SouthWest => Coor2DMut::new(west_x, 1),
+            HexDirection::West => Coor2DMut::new(-1, 0),
+            HexDirection::NorthWest => Coor2DMut::new(west_x, -1),
+            HexDirection::NorthEast => Coor2DMut::new(east_x, -1),
+        }
+    }
+}
+
+/// Neighborhoods around a point in 3D. They do not contain the point itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Neighborhood3D {
+    /// The 6 points sharing a face with the point
+    Face,
+    /// The 18 points sharing a face or an edge with the point
+    Edge,
+    /// The 26 points of the cube around the point (sharing a face, an edge or a vertex)
+    Vertex,
 }
@@ -0,0 +1,244 @@
+//! Borrowed rectangular views into a [`GridArray`] (e.g. the tiles of a grid), a view can be
+//! rotated and flipped without copying the cells
+
+use std::fmt::Display;
+
+use crate::grid_array::GridArray;
+use crate::grid_iteration;
+use crate::grid_types::{Coor2D, Coor2DIndex};
+
+/// View of the rectangle of a grid starting at `x0`, `y0`, the coors of the view start at 0, 0
+#[derive(PartialEq, Debug)]
+pub struct GridView<'a, T: Default + Clone + Display> {
+    grid: &'a GridArray<T>,
+    x0: Coor2DIndex,
+    y0: Coor2DIndex,
+    /// width of the rectangle in the grid
+    width: Coor2DIndex,
+    /// height of the rectangle in the grid
+    height: Coor2DIndex,
+
+    // orientation: a view coor is flipped (in the view) and then transposed to the rectangle
+    flip_x: bool,
+    flip_y: bool,
+    transposed: bool,
+}
+
+// not derived: a view is copyable for every T
+#[allow(clippy::expl_impl_clone_on_copy)]
+impl<T: Default + Clone + Display> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Default + Clone + Display> Copy for GridView<'_, T> {}
+
+impl<T: Default + Clone + Display> Display for GridView<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.get_height() {
+            for x in 0..self.get_width() {
+                write!(f, "{}", self.get_unchecked(x, y))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, T: Default + Clone + Display> GridView<'a, T> {
+    /// View of the rectangle with the size `width` x `height` starting at `x0`, `y0`
+    /// #Panics panics if the rectangle is not inside the grid
+    pub fn new(
+        grid: &'a GridArray<T>,
+        x0: Coor2DIndex,
+        y0: Coor2DIndex,
+        width: Coor2DIndex,
+        height: Coor2DIndex,
+    ) -> Self {
+        assert!(
+            x0 + width <= grid.get_width() && y0 + height <= grid.get_height(),
+            "x0={x0} y0={y0} width={width} height={height} outside of {}x{}",
+            grid.get_width(),
+            grid.get_height()
+        );
+        GridView {
+            grid,
+            x0,
+            y0,
+            width,
+            height,
+            flip_x: false,
+            flip_y: false,
+            transposed: false,
+        }
+    }
+
+    /// `get_width` (of the view, after rotations)
+    pub fn get_width(&self) -> usize {
+        if self.transposed {
+            self.height
+        } else {
+            self.width
+        }
+    }
+
+    /// `get_height` (of the view, after rotations)
+    pub fn get_height(&self) -> usize {
+        if self.transposed {
+            self.width
+        } else {
+            self.height
+        }
+    }
+
+    /// Coor in the grid of x, y of the view
+    pub fn to_grid_coor(&self, x: Coor2DIndex, y: Coor2DIndex) -> Option<Coor2D> {
+        if x >= self.get_width() || y >= self.get_height() {
+            return None;
+        }
+        let x = if self.flip_x {
+            self.get_width() - 1 - x
+        } else {
+            x
+        };
+        let y = if self.flip_y {
+            self.get_height() - 1 - y
+        } else {
+            y
+        };
+        let (x, y) = if self.transposed { (y, x) } else { (x, y) };
+        Some(Coor2D::new(self.x0 + x, self.y0 + y))
+    }
+
+    /// get reference to element on x, y of the view
+    pub fn get(&self, x: Coor2DIndex, y: Coor2DIndex) -> Option<&'a T> {
+        let coor = self.to_grid_coor(x, y)?;
+        Some(self.grid.get_unchecked(coor.x, coor.y))
+    }
+
+    /// get reference to element on x, y of the view
+    pub fn get_unchecked(&self, x: Coor2DIndex, y: Coor2DIndex) -> &'a T {
+        self.get(x, y).expect("coor inside of the view")
+    }
+
+    /// return all indexes (of the view)
+    pub fn all_indexes(&self) -> impl Iterator<Item = Coor2D> {
+        grid_iteration::all_cells(self.get_width(), self.get_height())
+    }
+
+    /// return all elements (with the coors of the view)
+    pub fn all_cells(&self) -> impl Iterator<Item = (Coor2D, &'a T)> + '_ {
+        self.all_indexes()
+            .map(|coor| (coor.clone(), self.get_unchecked(coor.x, coor.y)))
+    }
+
+    /// Copy of the view as grid (with topology and neighborhood of the viewed grid)
+    pub fn to_grid_array(&self) -> GridArray<T> {
+        GridArray::from_2d_vec(
+            self.grid.get_topology(),
+            self.grid.get_neighborhood(),
+            (0..self.get_height())
+                .map(|y| {
+                    (0..self.get_width())
+                        .map(|x| self.get_unchecked(x, y).clone())
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
+    /// flip_horizontal
+    pub fn flip_horizontal(&mut self) {
+        self.flip_x = !self.flip_x;
+    }
+
+    /// flip_vertical
+    pub fn flip_vertical(&mut self) {
+        self.flip_y = !self.flip_y;
+    }
+
+    /// transpose
+    pub fn transpose(&mut self) {
+        std::mem::swap(&mut self.flip_x, &mut self.flip_y);
+        self.transposed = !self.transposed;
+    }
+
+    /// rotate_cw
+    pub fn rotate_cw(&mut self) {
+        // rotate clockwise by 90° (like GridArray::rotate_cw)
+        self.transpose();
+        self.flip_horizontal();
+    }
+
+    /// rotate_ccw
+    pub fn rotate_ccw(&mut self) {
+        // rotate counter clockwise by 90°
+        self.transpose();
+        self.flip_vertical();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_types::{Neighborhood, Topology};
+
+    fn grid() -> GridArray<char> {
+        GridArray::from_newline_separated_string(
+            Topology::Bounded,
+            Neighborhood::Orthogonal,
+            "abcd\nefgh\nijkl\n",
+        )
+    }
+
+    #[test]
+    fn views() {
+        let grid = grid();
+        let mut view = grid.view(1, 0, 3, 2);
+        assert_eq!(view.to_string(), "bcd\nfgh\n");
+        assert_eq!(view.get(2, 1), Some(&'h'));
+        assert_eq!(view.get(3, 0), None);
+        assert_eq!(view.to_grid_coor(0, 1), Some(Coor2D::new(1, 1)));
+
+        // same results as transforming a copy
+        let transforms = [
+            (
+                GridView::rotate_cw as fn(&mut _),
+                GridArray::rotate_cw as fn(&mut _),
+            ),
+            (GridView::rotate_ccw, GridArray::rotate_ccw),
+            (GridView::flip_horizontal, GridArray::flip_horizontal),
+            (GridView::flip_vertical, GridArray::flip_vertical),
+            (GridView::transpose, GridArray::transpose),
+        ];
+        for (transform_view, transform_copy) in transforms {
+            for _ in 0..3 {
+                let mut copy = view.to_grid_array();
+                transform_view(&mut view);
+                transform_copy(&mut copy);
+                assert_eq!(view.to_grid_array(), copy);
+            }
+        }
+
+        let mut view = grid.view(0, 0, 4, 3);
+        view.rotate_cw();
+        assert_eq!(view.to_string(), "iea\njfb\nkgc\nlhd\n");
+        assert_eq!(view.to_grid_coor(2, 0), Some(Coor2D::new(0, 0)));
+        assert_eq!(view.all_cells().count(), 12);
+    }
+
+    #[test]
+    fn tiles() {
+        let grid = grid();
+        let tiles = grid.tiles(2, 1).collect::<Vec<_>>();
+        assert_eq!(tiles.len(), 6);
+        assert_eq!(tiles[1].0, Coor2D::new(1, 0));
+        assert_eq!(tiles[1].1.to_string(), "cd\n");
+        let (coor, mut tile) = tiles[4].clone();
+        assert_eq!(coor, Coor2D::new(0, 2));
+        tile.flip_horizontal();
+        assert_eq!(tile.to_string(), "ji\n");
+        assert_eq!(tile.to_grid_coor(0, 0), Some(Coor2D::new(1, 2)));
+    }
+}
@@ -1,4 +1,4 @@
-//! 2D Grid implementations
+//! 2D and 3D Grid implementations
 
 #![deny(clippy::all, clippy::pedantic)]
 #![allow(
@@ -7,13 +7,26 @@
     clippy::must_use_candidate
 )]
 #![deny(missing_docs)]
-#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::missing_panics_doc, clippy::missing_errors_doc)]
 #![allow(clippy::doc_markdown)]
 
 #[macro_use]
 extern crate derive_builder;
 
+pub mod draw;
 pub mod grid_array;
+pub mod grid_array_3d;
+pub mod grid_bits;
+pub mod grid_cube;
+pub mod grid_diff;
 pub mod grid_hashmap;
+pub mod grid_hashmap_3d;
 pub mod grid_iteration;
+pub mod grid_pathfinding;
+pub mod grid_regions;
+pub mod grid_text;
+pub mod grid_trait;
 pub mod grid_types;
+pub mod grid_view;
+#[cfg(feature = "render")]
+pub mod render;
@@ -0,0 +1,425 @@
+//! Renders grids into PNG images and animated GIFs (feature "render"),
+//! the colors of the cells are given by a palette function
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use fxhash::FxHashMap;
+
+use crate::grid_array::GridArray;
+use crate::grid_hashmap::GridHashMap;
+use crate::grid_trait::Grid;
+use crate::grid_types::Coor2DMut;
+
+/// Color as [red, green, blue]
+pub type Rgb = [u8; 3];
+
+/// RGB image, one pixel per cell (or `scale`² pixels, see [`Image::scaled`])
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Image with the color of every pixel given by `color(x, y)`
+    pub fn from_fn(
+        width: usize,
+        height: usize,
+        mut color: impl FnMut(usize, usize) -> Rgb,
+    ) -> Self {
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| color(x, y))
+            .collect();
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Image of any grid between its min and max (see [`Grid::min_max`], empty without them),
+    /// missing cells are passed as None to the palette
+    pub fn from_grid<G: Grid>(grid: &G, mut palette: impl FnMut(Option<&G::Cell>) -> Rgb) -> Self {
+        let Some((min, max)) = grid.min_max() else {
+            return Self::from_fn(0, 0, |_, _| [0, 0, 0]);
+        };
+        let ((min_x, min_y), (max_x, max_y)) = (G::to_xy(&min), G::to_xy(&max));
+        let size = |from: isize, to: isize| usize::try_from(to - from + 1).unwrap_or(0);
+        Self::from_fn(size(min_x, max_x), size(min_y, max_y), |x, y| {
+            let offset = |value: usize| isize::try_from(value).unwrap();
+            palette(
+                G::from_xy(min_x + offset(x), min_y + offset(y)).and_then(|coor| grid.cell(&coor)),
+            )
+        })
+    }
+
+    /// Image of a grid, one pixel per cell
+    pub fn from_grid_array<T: Default + Clone + std::fmt::Display>(
+        grid: &GridArray<T>,
+        mut palette: impl FnMut(&T) -> Rgb,
+    ) -> Self {
+        Self::from_fn(grid.get_width(), grid.get_height(), |x, y| {
+            palette(grid.get_unchecked(x, y))
+        })
+    }
+
+    /// Image of the cells between `min` and `max` (both included, e.g. the result of `get_min_max`),
+    /// missing cells are passed as None to the palette
+    pub fn from_grid_hashmap<T: Default + Clone + std::fmt::Display>(
+        grid: &GridHashMap<T>,
+        (min, max): (Coor2DMut<isize>, Coor2DMut<isize>),
+        mut palette: impl FnMut(Option<&T>) -> Rgb,
+    ) -> Self {
+        let size = |from: isize, to: isize| usize::try_from(to - from + 1).unwrap_or(0);
+        Self::from_fn(size(min.x, max.x), size(min.y, max.y), |x, y| {
+            let offset = |value: usize| isize::try_from(value).unwrap();
+            palette(grid.get(&Coor2DMut::new(min.x + offset(x), min.y + offset(y))))
+        })
+    }
+
+    /// `get_width`
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    /// `get_height`
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    /// color of pixel x, y
+    pub fn get(&self, x: usize, y: usize) -> Option<&Rgb> {
+        (x < self.width && y < self.height).then(|| &self.pixels[y * self.width + x])
+    }
+
+    /// Every pixel becomes a square of `scale` x `scale` pixels (small grids are hard to see otherwise)
+    #[must_use]
+    pub fn scaled(&self, scale: usize) -> Self {
+        Self::from_fn(self.width * scale, self.height * scale, |x, y| {
+            self.pixels[(y / scale) * self.width + x / scale]
+        })
+    }
+
+    /// Saves the image as PNG
+    pub fn save_png(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let file = BufWriter::new(File::create(path)?);
+        let mut encoder = png::Encoder::new(file, to_u32(self.width)?, to_u32(self.height)?);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer
+            .write_image_data(&self.pixels.concat())
+            .map_err(io::Error::other)?;
+        writer.finish().map_err(io::Error::other)
+    }
+
+    /// Frame with a local palette if the image has at most 256 colors (lossless), otherwise
+    /// the colors are reduced
+    fn to_gif_frame(&self) -> io::Result<gif::Frame<'static>> {
+        let (width, height) = (to_u16(self.width)?, to_u16(self.height)?);
+        let mut colors = FxHashMap::default();
+        let indexes = self
+            .pixels
+            .iter()
+            .map(|pixel| {
+                let next_index = colors.len();
+                *colors.entry(*pixel).or_insert(next_index)
+            })
+            .collect::<Vec<_>>();
+        if colors.len() > 256 {
+            return Ok(gif::Frame::from_rgb_speed(
+                width,
+                height,
+                &self.pixels.concat(),
+                10,
+            ));
+        }
+        let mut palette = vec![[0; 3]; colors.len()];
+        for (color, index) in colors {
+            palette[index] = color;
+        }
+        let indexes = indexes
+            .into_iter()
+            .map(|index| u8::try_from(index).expect("at most 256 colors"))
+            .collect::<Vec<_>>();
+        Ok(gif::Frame::from_palette_pixels(
+            width,
+            height,
+            &indexes,
+            &palette.concat(),
+            None,
+        ))
+    }
+}
+
+fn to_u16(size: usize) -> io::Result<u16> {
+    u16::try_from(size)
+        .map_err(|_| io::Error::other(format!("{size} pixels are too large for a GIF")))
+}
+
+fn to_u32(size: usize) -> io::Result<u32> {
+    u32::try_from(size)
+        .map_err(|_| io::Error::other(format!("{size} pixels are too large for a PNG")))
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+/// Records the frames of a simulation as animated GIF, each frame is written to the file when
+/// it is added (the file is created with the first frame)
+pub struct GifRecorder {
+    path: PathBuf,
+    delay: u16,
+    scale: usize,
+    repeat: bool,
+    /// width and height of the animation, None: the size of the first frame
+    size: Option<(u16, u16)>,
+    encoder: Option<gif::Encoder<BufWriter<File>>>,
+    frames: usize,
+}
+
+impl std::fmt::Debug for GifRecorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GifRecorder")
+            .field("path", &self.path)
+            .field("delay", &self.delay)
+            .field("scale", &self.scale)
+            .field("repeat", &self.repeat)
+            .field("size", &self.size)
+            .field("frames", &self.frames)
+            .finish_non_exhaustive()
+    }
+}
+
+impl GifRecorder {
+    /// Recorder writing to `path` (10ms per frame, no scaling, played once)
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        GifRecorder {
+            path: path.into(),
+            delay: 1,
+            scale: 1,
+            repeat: false,
+            size: None,
+            encoder: None,
+            frames: 0,
+        }
+    }
+
+    /// Shows each frame for `delay` hundredths of a second
+    #[must_use]
+    pub fn with_delay(mut self, delay: u16) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Scales all frames (see [`Image::scaled`])
+    #[must_use]
+    pub fn with_scale(mut self, scale: usize) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+
+    /// Plays the animation in an endless loop
+    #[must_use]
+    pub fn with_repeat(mut self, repeat: bool) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Size of the animation in pixels (after scaling), needed if later frames are larger than
+    /// the first one
+    #[must_use]
+    pub fn with_size(mut self, width: u16, height: u16) -> Self {
+        self.size = Some((width, height));
+        self
+    }
+
+    /// `get_path`
+    pub fn get_path(&self) -> &Path {
+        &self.path
+    }
+
+    /// number of recorded frames
+    pub fn len(&self) -> usize {
+        self.frames
+    }
+
+    /// no frame recorded yet
+    pub fn is_empty(&self) -> bool {
+        self.frames == 0
+    }
+
+    fn open_encoder(&mut self, width: u16, height: u16) -> io::Result<()> {
+        let (width, height) = *self.size.get_or_insert((width, height));
+        let file = BufWriter::new(File::create(&self.path)?);
+        let mut encoder = gif::Encoder::new(file, width, height, &[]).map_err(io::Error::other)?;
+        let repeat = if self.repeat {
+            gif::Repeat::Infinite
+        } else {
+            gif::Repeat::Finite(0)
+        };
+        encoder.set_repeat(repeat).map_err(io::Error::other)?;
+        self.encoder = Some(encoder);
+        Ok(())
+    }
+
+    /// Adds a frame aligned at the top left corner and writes it to the file,
+    /// fails if it is larger than the animation (see [`GifRecorder::with_size`])
+    pub fn add_frame(&mut self, image: &Image) -> io::Result<()> {
+        let mut frame = if self.scale > 1 {
+            image.scaled(self.scale).to_gif_frame()?
+        } else {
+            image.to_gif_frame()?
+        };
+        frame.delay = self.delay;
+        if self.encoder.is_none() {
+            self.open_encoder(frame.width, frame.height)?;
+        }
+        let (width, height) = self.size.unwrap();
+        if frame.width > width || frame.height > height {
+            return Err(io::Error::other(format!(
+                "frame of {}x{} pixels is larger than the animation of {width}x{height} pixels",
+                frame.width, frame.height
+            )));
+        }
+        let encoder = self.encoder.as_mut().unwrap();
+        encoder.write_frame(&frame).map_err(io::Error::other)?;
+        self.frames += 1;
+        Ok(())
+    }
+
+    /// Adds the current state of any grid as frame (see [`Image::from_grid`])
+    pub fn record_grid<G: Grid>(
+        &mut self,
+        grid: &G,
+        palette: impl FnMut(Option<&G::Cell>) -> Rgb,
+    ) -> io::Result<()> {
+        self.add_frame(&Image::from_grid(grid, palette))
+    }
+
+    /// Adds the current state of a grid as frame
+    pub fn record_grid_array<T: Default + Clone + std::fmt::Display>(
+        &mut self,
+        grid: &GridArray<T>,
+        palette: impl FnMut(&T) -> Rgb,
+    ) -> io::Result<()> {
+        self.add_frame(&Image::from_grid_array(grid, palette))
+    }
+
+    /// Adds the cells of a grid between `min` and `max` as frame (fixed bounds keep the frames aligned)
+    pub fn record_grid_hashmap<T: Default + Clone + std::fmt::Display>(
+        &mut self,
+        grid: &GridHashMap<T>,
+        min_max: (Coor2DMut<isize>, Coor2DMut<isize>),
+        palette: impl FnMut(Option<&T>) -> Rgb,
+    ) -> io::Result<()> {
+        self.add_frame(&Image::from_grid_hashmap(grid, min_max, palette))
+    }
+
+    /// Finishes the file (an empty animation if no frame was added), later frames start a new
+    /// file
+    pub fn save(&mut self) -> io::Result<()> {
+        if self.encoder.is_none() {
+            self.open_encoder(1, 1)?;
+        }
+        let mut file = self.encoder.take().unwrap().into_inner()?;
+        file.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid_types::{Neighborhood, Topology};
+
+    #[allow(clippy::trivially_copy_pass_by_ref)] // used as palette of a GridArray<char>
+    fn palette(cell: &char) -> Rgb {
+        match cell {
+            '#' => [255, 255, 255],
+            _ => [0, 0, 0],
+        }
+    }
+
+    fn temp_file(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("grid_render_{}_{name}", std::process::id()))
+    }
+
+    #[test]
+    fn images() {
+        let grid = GridArray::from_newline_separated_string(
+            Topology::Bounded,
+            Neighborhood::Square,
+            "#..\n.#.\n",
+        );
+        let image = Image::from_grid_array(&grid, palette);
+        assert_eq!((image.get_width(), image.get_height()), (3, 2));
+        assert_eq!(image.get(1, 1), Some(&[255, 255, 255]));
+        assert_eq!(image.get(3, 0), None);
+        let scaled = image.scaled(2);
+        assert_eq!(scaled.get_width(), 6);
+        assert_eq!(scaled.get(3, 3), Some(&[255, 255, 255]));
+
+        let mut sparse =
+            GridHashMap::from_newline_separated_string(Neighborhood::Square, "#.\n.#\n");
+        sparse.remove(&Coor2DMut::new(1, 0));
+        let image = Image::from_grid_hashmap(
+            &sparse,
+            (Coor2DMut::new(-1, 0), Coor2DMut::new(1, 1)),
+            |cell| cell.map_or([255, 0, 0], palette),
+        );
+        assert_eq!(image.get_width(), 3);
+        assert_eq!(image.get(0, 0), Some(&[255, 0, 0]));
+        assert_eq!(image.get(1, 0), Some(&[255, 255, 255]));
+        assert_eq!(image.get(2, 0), Some(&[255, 0, 0]));
+        let image = Image::from_grid(&sparse, |cell| cell.map_or([255, 0, 0], palette));
+        assert_eq!((image.get_width(), image.get_height()), (2, 2));
+        assert_eq!(image.get(1, 0), Some(&[255, 0, 0]));
+        let empty: GridArray<char> = crate::grid_array::GridArrayBuilder::default()
+            .width(0)
+            .height(2)
+            .build()
+            .unwrap();
+        let empty_image = Image::from_grid(&empty, |cell| cell.map_or([255, 0, 0], palette));
+        assert_eq!((empty_image.get_width(), empty_image.get_height()), (0, 0));
+
+        let file = temp_file("image.png");
+        image.save_png(&file).unwrap();
+        assert!(std::fs::read(&file).unwrap().starts_with(b"\x89PNG"));
+        std::fs::remove_file(file).unwrap();
+    }
+
+    #[test]
+    fn animation() {
+        let mut grid = GridArray::from_newline_separated_string(
+            Topology::Bounded,
+            Neighborhood::Square,
+            "...\n...\n",
+        );
+        let file = temp_file("animation.gif");
+        let mut recorder = GifRecorder::new(&file).with_scale(4).with_delay(5);
+        for x in 0..3 {
+            grid.set(x, 0, '#');
+            recorder.record_grid_array(&grid, palette).unwrap();
+        }
+        assert_eq!(recorder.len(), 3);
+        let mut larger = grid.clone();
+        larger.transpose();
+        assert!(recorder.record_grid_array(&larger, palette).is_err());
+        recorder.save().unwrap();
+
+        let mut decoder = gif::DecodeOptions::new()
+            .read_info(File::open(&file).unwrap())
+            .unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (12, 8));
+        let mut frames = 0;
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!(frame.delay, 5);
+            frames += 1;
+        }
+        assert_eq!(frames, 3);
+        std::fs::remove_file(file).unwrap();
+    }
+}
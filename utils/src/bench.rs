@@ -0,0 +1,406 @@
+//! Benchmark harness (stable Rust): warm-up, repeated samples, statistics split into parse and
+//! solve time and a JSON baseline to detect regressions
+
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{InputError, InputResult};
+
+////////////////////////////////////////////////////////////////////////////////////
+
+/// How long to warm up and how many samples to take
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// the function is run (unmeasured) for this time before sampling starts
+    pub warm_up: Duration,
+    /// sampling stops after this time (once `min_samples` are taken) ...
+    pub measurement_time: Duration,
+    /// ... but takes at least this many samples
+    pub min_samples: usize,
+    /// ... and at most this many samples
+    pub max_samples: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warm_up: Duration::from_millis(500),
+            measurement_time: Duration::from_secs(3),
+            min_samples: 10,
+            max_samples: 1000,
+        }
+    }
+}
+
+thread_local! {
+    /// (nesting depth, time spent in parse phases since the last reset)
+    static PARSE_PHASE: Cell<(usize, Duration)> = const { Cell::new((0, Duration::ZERO)) };
+}
+
+/// Marks `f` as parsing, the time spent in it is reported as parse time by [`measure`].
+/// Nested calls are only counted once, calls on other threads count as solve time.
+pub fn parse_phase<T>(f: impl FnOnce() -> T) -> T {
+    PARSE_PHASE.with(|phase| {
+        let (depth, parse_time) = phase.get();
+        phase.set((depth + 1, parse_time));
+        let start = Instant::now();
+        let result = f();
+        let elapsed = start.elapsed();
+        let (depth, parse_time) = phase.get();
+        if depth == 1 {
+            phase.set((0, parse_time + elapsed));
+        } else {
+            phase.set((depth - 1, parse_time));
+        }
+        result
+    })
+}
+
+/// Returns the parse time since the last call and resets it
+fn take_parse_time() -> Duration {
+    PARSE_PHASE.with(|phase| {
+        let (_, parse_time) = phase.get();
+        // a panic inside a parse phase may leave the depth behind, every sample starts fresh
+        phase.set((0, Duration::ZERO));
+        parse_time
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+/// Statistics over the samples of one measurement (times in nanoseconds)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    /// number of samples
+    pub samples: usize,
+    /// fastest sample
+    pub min_ns: f64,
+    /// median of the samples
+    pub median_ns: f64,
+    /// 95th percentile of the samples
+    pub p95_ns: f64,
+    /// mean of the samples
+    pub mean_ns: f64,
+    /// standard deviation of the samples
+    pub stddev_ns: f64,
+}
+
+impl Stats {
+    /// Calculates the statistics of given samples (must not be empty)
+    pub fn new(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+        let mut nanos = samples
+            .iter()
+            .map(|d| d.as_secs_f64() * 1e9)
+            .collect::<Vec<_>>();
+        nanos.sort_by(f64::total_cmp);
+        #[allow(clippy::cast_precision_loss)]
+        let count = nanos.len() as f64;
+        let mean_ns = nanos.iter().sum::<f64>() / count;
+        let variance = nanos.iter().map(|n| (n - mean_ns).powi(2)).sum::<f64>() / count;
+        Stats {
+            samples: nanos.len(),
+            min_ns: nanos[0],
+            median_ns: percentile(&nanos, 50),
+            p95_ns: percentile(&nanos, 95),
+            mean_ns,
+            stddev_ns: variance.sqrt(),
+        }
+    }
+
+    /// Median as a Duration
+    pub fn median(&self) -> Duration {
+        nanos_to_duration(self.median_ns)
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "median {:?}, p95 {:?}, stddev {:?}",
+            self.median(),
+            nanos_to_duration(self.p95_ns),
+            nanos_to_duration(self.stddev_ns)
+        )
+    }
+}
+
+/// Nearest rank percentile of sorted values
+fn percentile(sorted: &[f64], percent: usize) -> f64 {
+    let rank = (sorted.len() * percent).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+fn nanos_to_duration(nanos: f64) -> Duration {
+    Duration::from_secs_f64(nanos.max(0.0) / 1e9)
+}
+
+/// Statistics of one benchmarked function
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    /// whole run
+    pub total: Stats,
+    /// time spent in [`parse_phase`] (None if the function has no marked parse phase)
+    pub parse: Option<Stats>,
+    /// total minus parse time
+    pub solve: Stats,
+}
+
+impl Display for Measurement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({} samples)", self.total, self.total.samples)?;
+        if let Some(parse) = &self.parse {
+            write!(
+                f,
+                ", parse median {:?}, solve median {:?}",
+                parse.median(),
+                self.solve.median()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs `f` for the warm-up time and then samples it as configured
+pub fn measure<T>(config: &BenchConfig, mut f: impl FnMut() -> T) -> Measurement {
+    let warm_up_start = Instant::now();
+    loop {
+        black_box(f());
+        if warm_up_start.elapsed() >= config.warm_up {
+            break;
+        }
+    }
+
+    let (mut totals, mut parses) = (Vec::new(), Vec::new());
+    let measurement_start = Instant::now();
+    while totals.len() < config.max_samples.max(1)
+        && (totals.len() < config.min_samples
+            || measurement_start.elapsed() < config.measurement_time)
+    {
+        take_parse_time();
+        let start = Instant::now();
+        black_box(f());
+        let total = start.elapsed();
+        totals.push(total);
+        parses.push(take_parse_time());
+    }
+
+    let solves = totals
+        .iter()
+        .zip(&parses)
+        .map(|(total, parse)| total.saturating_sub(*parse))
+        .collect::<Vec<_>>();
+    Measurement {
+        total: Stats::new(&totals),
+        parse: parses
+            .iter()
+            .any(|parse| !parse.is_zero())
+            .then(|| Stats::new(&parses)),
+        solve: Stats::new(&solves),
+    }
+}
+
+/// Time of a single run, split like a [`Measurement`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    /// whole run
+    pub total: Duration,
+    /// time spent in [`parse_phase`] (None if the function has no marked parse phase)
+    pub parse: Option<Duration>,
+}
+
+impl Timing {
+    /// total minus parse time
+    pub fn solve(&self) -> Duration {
+        self.total.saturating_sub(self.parse.unwrap_or_default())
+    }
+}
+
+impl Display for Timing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.total)?;
+        if let Some(parse) = self.parse {
+            write!(f, ", parse {parse:?}, solve {:?}", self.solve())?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs `f` once (no warm-up, see [`measure`] for benchmarks) and returns its result with the time
+pub fn time<T>(f: impl FnOnce() -> T) -> (T, Timing) {
+    take_parse_time();
+    let start = Instant::now();
+    let result = f();
+    let total = start.elapsed();
+    let parse = take_parse_time();
+    let timing = Timing {
+        total,
+        parse: (!parse.is_zero()).then_some(parse),
+    };
+    (result, timing)
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+/// Saved measurements (e.g. of all parts of a day) to compare later runs against
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    /// measurement per benchmark name
+    pub measurements: BTreeMap<String, Measurement>,
+}
+
+impl Baseline {
+    /// Loads a baseline from a JSON file
+    pub fn load(file_name: impl AsRef<Path>) -> InputResult<Self> {
+        let file_name = file_name.as_ref().to_string_lossy();
+        let content = crate::try_file_to_string(&file_name)?;
+        serde_json::from_str(&content).map_err(|error| {
+            InputError::parse(error.to_string(), "")
+                .at_line(error.line())
+                .in_file(&file_name)
+        })
+    }
+
+    /// Saves the baseline as JSON file
+    pub fn save(&self, file_name: impl AsRef<Path>) -> InputResult<()> {
+        let json = serde_json::to_string_pretty(self).expect("baseline is serializable");
+        fs::write(&file_name, json + "\n").map_err(|source| InputError::Io {
+            file: file_name.as_ref().to_string_lossy().to_string(),
+            line: None,
+            source,
+        })
+    }
+
+    /// Compares a new measurement with the one saved under `name` (if there is one)
+    pub fn compare(&self, name: &str, measurement: &Measurement) -> Option<Comparison> {
+        self.measurements.get(name).map(|baseline| Comparison {
+            baseline: baseline.total.clone(),
+            current: measurement.total.clone(),
+        })
+    }
+}
+
+/// Comparison of a measurement with its baseline
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    /// total time of the baseline
+    pub baseline: Stats,
+    /// total time of the current run
+    pub current: Stats,
+}
+
+impl Comparison {
+    /// Relative change of the median (0.1 = 10% slower)
+    pub fn change(&self) -> f64 {
+        self.current.median_ns / self.baseline.median_ns - 1.0
+    }
+
+    /// The median got slower by more than `threshold` (relative) and left the noise of the
+    /// baseline (above its p95)
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold && self.current.median_ns > self.baseline.p95_ns
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:+.1}% (baseline median {:?})",
+            self.change() * 100.0,
+            self.baseline.median()
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats::new(&millis(&[5, 1, 4, 2, 3, 6, 7, 8, 9, 10]));
+        assert_eq!(stats.samples, 10);
+        assert_eq!(stats.median(), Duration::from_millis(5));
+        assert_eq!(nanos_to_duration(stats.p95_ns), Duration::from_millis(10));
+        assert_eq!(
+            nanos_to_duration(stats.mean_ns),
+            Duration::from_micros(5500)
+        );
+        assert!((stats.stddev_ns - 2.872_281e6).abs() < 1.0);
+    }
+
+    #[test]
+    fn parse_and_solve_time() {
+        let config = BenchConfig {
+            warm_up: Duration::ZERO,
+            measurement_time: Duration::ZERO,
+            min_samples: 3,
+            max_samples: 3,
+        };
+        let measurement = measure(&config, || {
+            parse_phase(|| parse_phase(|| std::thread::sleep(Duration::from_millis(2))));
+            std::thread::sleep(Duration::from_millis(1));
+        });
+        assert_eq!(measurement.total.samples, 3);
+        let parse = measurement.parse.unwrap().median();
+        // only lower bounds of the wall-clock times, a loaded machine may take longer
+        assert!(parse >= Duration::from_millis(2) && parse < measurement.total.median());
+        assert!(measurement.solve.median() >= Duration::from_millis(1));
+
+        assert!(measure(&config, || 42).parse.is_none());
+
+        let (result, timing) = time(|| {
+            parse_phase(|| std::thread::sleep(Duration::from_millis(2)));
+            std::thread::sleep(Duration::from_millis(1));
+            42
+        });
+        assert_eq!(result, 42);
+        assert!(timing.parse.unwrap() >= Duration::from_millis(2));
+        assert!(timing.solve() >= Duration::from_millis(1));
+        assert!(time(|| 42).1.parse.is_none());
+    }
+
+    #[test]
+    fn regressions() {
+        let measurement = |values: &[u64]| Measurement {
+            total: Stats::new(&millis(values)),
+            parse: None,
+            solve: Stats::new(&millis(values)),
+        };
+        let mut baseline = Baseline::default();
+        baseline
+            .measurements
+            .insert("part1".into(), measurement(&[10, 10, 11, 12]));
+
+        assert!(baseline.compare("part2", &measurement(&[20])).is_none());
+        let slower = baseline.compare("part1", &measurement(&[15])).unwrap();
+        assert_eq!(slower.to_string(), "+50.0% (baseline median 10ms)");
+        assert!(slower.is_regression(0.1));
+        // within the noise of the baseline
+        assert!(!baseline
+            .compare("part1", &measurement(&[12]))
+            .unwrap()
+            .is_regression(0.1));
+
+        let file =
+            std::env::temp_dir().join(format!("utils_bench_baseline_{}.json", std::process::id()));
+        baseline.save(&file).unwrap();
+        assert_eq!(Baseline::load(&file).unwrap(), baseline);
+        fs::remove_file(file).unwrap();
+    }
+}
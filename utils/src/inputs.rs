@@ -0,0 +1,235 @@
+//! Locates the input files of a day (`<root>/dayXY/<name>.txt`) independent of the working directory
+
+use std::env;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::{FileCache, InputError, InputKind, InputResult, YEAR};
+
+/// Environment variable with the inputs root, overrides all other roots
+pub const INPUTS_ENV: &str = "AOC_INPUTS";
+
+/// Input file of a day
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InputFile {
+    /// real puzzle input (input.txt)
+    Input,
+    /// example from the puzzle description (test.txt)
+    Test,
+    /// second example (test2.txt)
+    Test2,
+    /// any other file of the day, e.g. the input of another user (<name>.txt)
+    Named(String),
+}
+
+impl InputFile {
+    /// File name without ".txt"
+    pub fn name(&self) -> &str {
+        match self {
+            InputFile::Input => "input",
+            InputFile::Test => "test",
+            InputFile::Test2 => "test2",
+            InputFile::Named(name) => name,
+        }
+    }
+
+    /// Kind of the input, files starting with "test" are examples
+    pub fn kind(&self) -> InputKind {
+        if self.name().starts_with("test") {
+            InputKind::Example
+        } else {
+            InputKind::Puzzle
+        }
+    }
+}
+
+impl From<InputKind> for InputFile {
+    fn from(kind: InputKind) -> Self {
+        match kind {
+            InputKind::Example => InputFile::Test,
+            InputKind::Puzzle => InputFile::Input,
+        }
+    }
+}
+
+impl FromStr for InputFile {
+    type Err = InputError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "input" => Ok(InputFile::Input),
+            "test" => Ok(InputFile::Test),
+            "test2" => Ok(InputFile::Test2),
+            _ if !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') =>
+            {
+                Ok(InputFile::Named(name.to_string()))
+            }
+            _ => Err(InputError::parse(
+                "input name must be a file name without extension",
+                name,
+            )),
+        }
+    }
+}
+
+impl Display for InputFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Finds the files of a day in one or more roots (first match wins)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputResolver {
+    roots: Vec<PathBuf>,
+}
+
+impl Default for InputResolver {
+    fn default() -> Self {
+        Self::from_env()
+    }
+}
+
+impl InputResolver {
+    /// Uses the root given in `AOC_INPUTS`, otherwise the workspace root and then the working directory
+    pub fn from_env() -> Self {
+        Self::from_override(env::var_os(INPUTS_ENV).map(PathBuf::from))
+    }
+
+    fn from_override(root: Option<PathBuf>) -> Self {
+        if let Some(root) = root {
+            return Self::with_root(root);
+        }
+        let mut roots = vec![workspace_root()];
+        if let Ok(current_dir) = env::current_dir() {
+            if !roots.contains(&current_dir) {
+                roots.push(current_dir);
+            }
+        }
+        InputResolver { roots }
+    }
+
+    /// Uses only the given root
+    pub fn with_root(root: impl Into<PathBuf>) -> Self {
+        InputResolver {
+            roots: vec![root.into()],
+        }
+    }
+
+    /// Roots in the order they are tried
+    pub fn roots(&self) -> &[PathBuf] {
+        &self.roots
+    }
+
+    /// All paths that are tried for the file of a day (in order)
+    pub fn candidates(&self, day: u8, file: &InputFile) -> Vec<PathBuf> {
+        self.roots
+            .iter()
+            .map(|root| day_folder(root, day).join(format!("{}.txt", file.name())))
+            .collect()
+    }
+
+    /// Returns the first existing path of the file of a day,
+    /// the error lists all tried paths
+    pub fn resolve(&self, day: u8, file: &InputFile) -> InputResult<PathBuf> {
+        let tried = self.candidates(day, file);
+        tried
+            .iter()
+            .find(|path| path.is_file())
+            .cloned()
+            .ok_or_else(|| InputError::NotFound {
+                what: format!("{file} of day {day}"),
+                tried,
+            })
+    }
+
+    /// Returns the first existing folder of a day (containing e.g. answers.toml)
+    pub fn resolve_folder(&self, day: u8) -> InputResult<PathBuf> {
+        let tried = self
+            .roots
+            .iter()
+            .map(|root| day_folder(root, day))
+            .collect::<Vec<_>>();
+        tried
+            .iter()
+            .find(|path| path.is_dir())
+            .cloned()
+            .ok_or_else(|| InputError::NotFound {
+                what: format!("folder of day {day}"),
+                tried,
+            })
+    }
+}
+
+/// Resolves the file of a day with the default resolver (see [`InputResolver::from_env`]),
+/// a puzzle input missing there is taken from the input cache (see [`FileCache::from_env`])
+pub fn input_file(day: u8, file: &InputFile) -> InputResult<String> {
+    let path = match InputResolver::from_env().resolve(day, file) {
+        Err(InputError::NotFound { what, mut tried }) if *file == InputFile::Input => {
+            FileCache::from_env()
+                .cached_file(YEAR, day)
+                .map_err(|error| match error {
+                    InputError::NotFound {
+                        tried: cache_tried, ..
+                    } => {
+                        tried.extend(cache_tried);
+                        InputError::NotFound { what, tried }
+                    }
+                    error => error,
+                })?
+        }
+        resolved => resolved?,
+    };
+    Ok(path.to_string_lossy().to_string())
+}
+
+fn day_folder(root: &Path, day: u8) -> PathBuf {
+    root.join(format!("day{day:02}"))
+}
+
+/// Root of the workspace (parent of the utils crate, fixed at compile time)
+fn workspace_root() -> PathBuf {
+    let utils = Path::new(env!("CARGO_MANIFEST_DIR"));
+    utils.parent().unwrap_or(utils).to_path_buf()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names() {
+        assert_eq!("test2".parse::<InputFile>().unwrap(), InputFile::Test2);
+        assert_eq!(
+            "input_bob".parse::<InputFile>().unwrap(),
+            InputFile::Named("input_bob".into())
+        );
+        assert!("../input".parse::<InputFile>().is_err());
+        assert_eq!(InputFile::Test2.kind(), InputKind::Example);
+        assert_eq!(InputFile::from(InputKind::Puzzle), InputFile::Input);
+    }
+
+    #[test]
+    fn resolve_independent_of_working_directory() {
+        let resolver = InputResolver::from_override(None);
+        assert_eq!(resolver.roots()[0], workspace_root());
+        let path = resolver.resolve(1, &InputFile::Test).unwrap();
+        assert!(path.ends_with("day01/test.txt"));
+        assert!(resolver.resolve_folder(25).unwrap().ends_with("day25"));
+    }
+
+    #[test]
+    fn override_lists_tried_paths() {
+        let resolver = InputResolver::from_override(Some("/no/inputs".into()));
+        assert_eq!(resolver.roots(), [PathBuf::from("/no/inputs")]);
+        let error = resolver.resolve(5, &InputFile::Test2).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "test2 of day 5 not found, tried: /no/inputs/day05/test2.txt"
+        );
+    }
+}
@@ -7,17 +7,30 @@
     clippy::must_use_candidate
 )]
 #![deny(missing_docs)]
-#![allow(clippy::missing_panics_doc)]
+#![allow(clippy::missing_panics_doc, clippy::missing_errors_doc)]
 #![allow(clippy::doc_markdown)]
 
 use std::cmp::Reverse;
 use std::fmt::Debug;
-use std::fs;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 use std::ops::RangeInclusive;
 use std::path::PathBuf;
-use std::time::Instant;
+
+pub mod bench;
+mod inputs;
+mod parsing;
+mod provider;
+mod solution;
+mod stream;
+pub use inputs::{input_file, InputFile, InputResolver, INPUTS_ENV};
+pub use parsing::{
+    try_file_to_lines, try_file_to_string, try_parse_input_items, try_str_to, InputError,
+    InputResult,
+};
+#[cfg(feature = "http")]
+pub use provider::HttpProvider;
+pub use provider::{FileCache, InputProvider, CACHE_ENV, SESSION_ENV, YEAR};
+pub use solution::{Answer, InputKind, Solution};
+pub use stream::InputReader;
 
 //use itertools::Itertools;
 
@@ -26,7 +39,8 @@ pub fn printlnit<T: Debug>(x: &T) {
     println!("{x:?}");
 }
 
-/// Allows cargo run to be called in dayXY and in root folder
+/// Allows cargo run to be called in dayXY and in root folder (for explicit file names, inputs of a day are
+/// better located with [`InputResolver`])
 pub fn correct_folder(file_name: &str) -> PathBuf {
     let mut file_path = PathBuf::from(file_name);
     if !file_path.exists() {
@@ -37,16 +51,25 @@ pub fn correct_folder(file_name: &str) -> PathBuf {
     file_path
 }
 
-/// Reads a file and return its content as a string
+/// Reads a file and return its content as a string (panics with the location on errors, see `try_file_to_string`)
 pub fn file_to_string(file_name: &str) -> String {
-    fs::read_to_string(correct_folder(file_name)).unwrap()
+    try_file_to_string(file_name).unwrap_or_else(|error| panic!("{error}"))
 }
 
-/// Reads a file, splits per newline and returns an iterator
+/// Reads a file, splits per newline and returns an iterator (panics with the location on errors, see `try_file_to_lines`)
 pub fn file_to_lines(file_name: &str) -> impl Iterator<Item = String> {
-    BufReader::new(File::open(correct_folder(file_name)).unwrap())
-        .lines()
-        .flatten()
+    try_file_to_lines(file_name)
+        .unwrap_or_else(|error| panic!("{error}"))
+        .map(|line| line.unwrap_or_else(|error| panic!("{error}")))
+}
+
+/// Reads a file lazily and returns an iterator over the chunks separated by empty lines
+/// (CRLF normalised, panics with the location on errors, see [`InputReader`])
+pub fn file_to_chunks(file_name: &str) -> impl Iterator<Item = String> {
+    InputReader::open(file_name)
+        .unwrap_or_else(|error| panic!("{error}"))
+        .chunks()
+        .map(|chunk| chunk.unwrap_or_else(|error| panic!("{error}")))
 }
 
 /// Converts an iterator with str to an iterator with "T"
@@ -106,21 +129,6 @@ where
         .collect()
 }
 
-/// Runs given function, prints result and used duration
-pub fn with_measure<T: Debug>(title: &str, f: fn() -> T) -> T {
-    let start = Instant::now();
-    let res = f();
-    let duration = start.elapsed();
-    println!(
-        "{} result: {:?} (elapsed time is: {:?} / {} millisecs)",
-        title,
-        res,
-        duration,
-        duration.as_secs_f32() * 1000.0
-    );
-    res
-}
-
 /// Returns a range from "from" to "to" (if to is smaller than from a range from "to" to "from" is returned)
 pub fn inclusive_range_always<T: PartialOrd>(from: T, to: T) -> RangeInclusive<T> {
     if from < to {
@@ -0,0 +1,295 @@
+//! Fallible reading and parsing of inputs, errors carry file, line, column and the offending text
+
+use std::fmt::Display;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::correct_folder;
+
+/// Result of reading or parsing an input
+pub type InputResult<T> = Result<T, InputError>;
+
+/// Error while reading or parsing an input
+#[derive(Debug)]
+pub enum InputError {
+    /// input could not be read
+    Io {
+        /// file name
+        file: String,
+        /// line (1-based) if the error happened while reading lines
+        line: Option<usize>,
+        /// underlying error
+        source: io::Error,
+    },
+    /// content of the input is invalid
+    Parse {
+        /// file name (if known)
+        file: Option<String>,
+        /// line (1-based, if known)
+        line: Option<usize>,
+        /// column (1-based, in chars, if known)
+        column: Option<usize>,
+        /// offending text
+        text: String,
+        /// what is wrong with the text
+        message: String,
+    },
+    /// input could not be downloaded
+    Download {
+        /// requested URL
+        url: String,
+        /// reason
+        message: String,
+    },
+    /// input could not be found
+    NotFound {
+        /// description of the searched input (e.g. "test2 of day 5")
+        what: String,
+        /// all paths that were tried
+        tried: Vec<PathBuf>,
+    },
+}
+
+impl InputError {
+    /// Creates a parse error for the offending text, location is added with the builder methods
+    pub fn parse(message: impl Display, text: impl Into<String>) -> Self {
+        InputError::Parse {
+            file: None,
+            line: None,
+            column: None,
+            text: text.into(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Sets the file name (if not yet set)
+    #[must_use]
+    pub fn in_file(mut self, file_name: &str) -> Self {
+        if let InputError::Parse { file, .. } = &mut self {
+            file.get_or_insert_with(|| file_name.to_string());
+        }
+        self
+    }
+
+    /// Sets the line (1-based, if not yet set)
+    #[must_use]
+    pub fn at_line(mut self, line_number: usize) -> Self {
+        if let InputError::Parse { line, .. } = &mut self {
+            line.get_or_insert(line_number);
+        }
+        self
+    }
+
+    /// Sets the column to the start of `part` inside the single line `line_text`,
+    /// a column already set (relative to `part`) is shifted to be relative to `line_text`
+    #[must_use]
+    pub fn in_line(mut self, line_text: &str, part: &str) -> Self {
+        if let (InputError::Parse { column, .. }, Some((_, part_column))) =
+            (&mut self, position_of(line_text, part))
+        {
+            *column = Some(column.map_or(part_column, |column| column + part_column - 1));
+        }
+        self
+    }
+
+    /// Sets line and column to the start of `part` inside the (multi line) `text` (if not yet set)
+    #[must_use]
+    pub fn located_in(mut self, text: &str, part: &str) -> Self {
+        if let (InputError::Parse { line, column, .. }, Some((part_line, part_column))) =
+            (&mut self, position_of(text, part))
+        {
+            line.get_or_insert(part_line);
+            column.get_or_insert(part_column);
+        }
+        self
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Io { file, line, source } => {
+                write!(f, "{file}")?;
+                if let Some(line) = line {
+                    write!(f, ":{line}")?;
+                }
+                write!(f, ": {source}")
+            }
+            InputError::Download { url, message } => write!(f, "{url}: {message}"),
+            InputError::NotFound { what, tried } => {
+                let tried = tried.iter().map(|path| path.display().to_string());
+                write!(
+                    f,
+                    "{what} not found, tried: {}",
+                    tried.collect::<Vec<_>>().join(", ")
+                )
+            }
+            InputError::Parse {
+                file,
+                line,
+                column,
+                text,
+                message,
+            } => {
+                // file:line:col, a column without a line is labeled to not read as a line
+                let column = match (line, column) {
+                    (Some(_), Some(column)) => Some(column.to_string()),
+                    (None, Some(column)) => Some(format!("col {column}")),
+                    (_, None) => None,
+                };
+                let location = [file.clone(), line.map(|line| line.to_string()), column]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>()
+                    .join(":");
+                if !location.is_empty() {
+                    write!(f, "{location}: ")?;
+                }
+                write!(f, "{message}")?;
+                if !text.is_empty() {
+                    write!(f, ": {text:?}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Io { source, .. } => Some(source),
+            InputError::Parse { .. }
+            | InputError::Download { .. }
+            | InputError::NotFound { .. } => None,
+        }
+    }
+}
+
+/// Returns (line, column) (both 1-based, column in chars) of `part` inside `text`,
+/// `part` must be a sub slice of `text`
+fn position_of(text: &str, part: &str) -> Option<(usize, usize)> {
+    let offset = (part.as_ptr() as usize).checked_sub(text.as_ptr() as usize)?;
+    let before = text.get(..offset)?;
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    Some((
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    ))
+}
+
+/// Reads a file and return its content as a string
+pub fn try_file_to_string(file_name: &str) -> InputResult<String> {
+    fs::read_to_string(correct_folder(file_name)).map_err(|source| InputError::Io {
+        file: file_name.to_string(),
+        line: None,
+        source,
+    })
+}
+
+/// Reads a file and returns an iterator over its lines, a failing line reports its line number
+pub fn try_file_to_lines(
+    file_name: &str,
+) -> InputResult<impl Iterator<Item = InputResult<String>>> {
+    let file = File::open(correct_folder(file_name)).map_err(|source| InputError::Io {
+        file: file_name.to_string(),
+        line: None,
+        source,
+    })?;
+    let file_name = file_name.to_string();
+    Ok(BufReader::new(file)
+        .lines()
+        .enumerate()
+        .map(move |(index, line)| {
+            line.map_err(|source| InputError::Io {
+                file: file_name.clone(),
+                line: Some(index + 1),
+                source,
+            })
+        }))
+}
+
+/// Converts a str to a type, the error contains the offending str (without location)
+pub fn try_str_to<T>(input: &str) -> InputResult<T>
+where
+    T: FromStr,
+    <T>::Err: Display,
+{
+    input
+        .parse::<T>()
+        .map_err(|error| InputError::parse(error, input))
+}
+
+/// Splits given String, trim each lines, filters empty lines and parse each line into wished type,
+/// the error contains line and column of the offending item
+pub fn try_parse_input_items<T>(contents: &str) -> InputResult<Vec<T>>
+where
+    T: FromStr,
+    <T>::Err: Display,
+{
+    contents
+        .split('\n')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|item| try_str_to(item).map_err(|error| error.located_in(contents, item)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_items() {
+        assert_eq!(
+            try_parse_input_items::<usize>("1\n 2 \n\n3\n").unwrap(),
+            vec![1, 2, 3]
+        );
+        let error = try_parse_input_items::<usize>("1\n2\n  x3\n4").unwrap_err();
+        assert_eq!(
+            error.in_file("day42/input.txt").to_string(),
+            "day42/input.txt:3:3: invalid digit found in string: \"x3\""
+        );
+    }
+
+    #[test]
+    fn location() {
+        let text = "first line\nsecond line";
+        let part = &text[18..];
+        let error = InputError::parse("bad", part).located_in(text, part);
+        assert_eq!(error.to_string(), "2:8: bad: \"line\"");
+
+        // the first line and file win, outer context only fills the gaps
+        let line = &text[11..];
+        let error = InputError::parse("bad", &line[7..])
+            .in_line(line, &line[7..])
+            .at_line(2)
+            .at_line(1)
+            .in_file("input.txt");
+        assert_eq!(error.to_string(), "input.txt:2:8: bad: \"line\"");
+
+        // nested parts shift the column to the enclosing line
+        let rule = &line[7..];
+        let error = InputError::parse("bad", &rule[2..])
+            .in_line(rule, &rule[2..])
+            .in_line(line, rule);
+        assert_eq!(error.to_string(), "col 10: bad: \"ne\"");
+        assert_eq!(
+            error.in_file("input.txt").to_string(),
+            "input.txt:col 10: bad: \"ne\""
+        );
+
+        assert_eq!(InputError::parse("bad", "x").to_string(), "bad: \"x\"");
+        assert_eq!(InputError::parse("bad", "").to_string(), "bad");
+    }
+
+    #[test]
+    fn missing_file() {
+        let error = try_file_to_string("does/not/exist.txt").unwrap_err();
+        assert!(matches!(error, InputError::Io { .. }));
+        assert!(error.to_string().starts_with("does/not/exist.txt: "));
+        assert!(try_file_to_lines("does/not/exist.txt").is_err());
+    }
+}
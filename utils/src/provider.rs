@@ -0,0 +1,294 @@
+//! Sources of puzzle inputs: the committed day folders, a local cache keyed by year/day and
+//! (with the feature "http") the Advent of Code website using the session token
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{InputError, InputFile, InputResolver, InputResult};
+
+/// Year of this repository
+pub const YEAR: u16 = 2022;
+
+/// Environment variable with the folder of the input cache
+pub const CACHE_ENV: &str = "AOC_CACHE";
+
+/// Environment variable with the session token (cookie "session" of adventofcode.com)
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Returns the puzzle input of a day
+pub trait InputProvider {
+    /// Content of the puzzle input of given year and day
+    fn fetch(&self, year: u16, day: u8) -> InputResult<String>;
+}
+
+/// Offline stand-in: reads the inputs from the day folders (see [`InputResolver`]), only for [`YEAR`]
+impl InputProvider for InputResolver {
+    fn fetch(&self, year: u16, day: u8) -> InputResult<String> {
+        if year != YEAR {
+            return Err(InputError::NotFound {
+                what: format!("input of {year} day {day} (only {YEAR} is available)"),
+                tried: Vec::new(),
+            });
+        }
+        let path = self.resolve(day, &InputFile::Input)?;
+        crate::try_file_to_string(&path.to_string_lossy())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+/// Inputs stored as `<folder>/<year>/dayXY.txt`, missing ones are fetched once from the source
+pub struct FileCache {
+    folder: PathBuf,
+    source: Option<Box<dyn InputProvider>>,
+}
+
+impl FileCache {
+    /// Cache in given folder without a source (offline, only already cached inputs)
+    pub fn new(folder: impl Into<PathBuf>) -> Self {
+        FileCache {
+            folder: folder.into(),
+            source: None,
+        }
+    }
+
+    /// Cache in `AOC_CACHE` or the user cache folder (e.g. `~/.cache/aoc`),
+    /// with the feature "http" missing inputs are downloaded if `AOC_SESSION` is set
+    pub fn from_env() -> Self {
+        let folder = env::var_os(CACHE_ENV).map_or_else(
+            || {
+                env::var_os("XDG_CACHE_HOME")
+                    .map(PathBuf::from)
+                    .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))
+                    .or_else(|| env::var_os("LOCALAPPDATA").map(PathBuf::from))
+                    .unwrap_or_else(env::temp_dir)
+                    .join("aoc")
+            },
+            PathBuf::from,
+        );
+        let cache = Self::new(folder);
+        #[cfg(feature = "http")]
+        if let Some(http) = HttpProvider::from_env() {
+            return cache.with_source(http);
+        }
+        cache
+    }
+
+    /// Fetches missing inputs from `source` and stores them
+    #[must_use]
+    pub fn with_source(mut self, source: impl InputProvider + 'static) -> Self {
+        self.source = Some(Box::new(source));
+        self
+    }
+
+    /// Path of the cached input of a day
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.folder
+            .join(year.to_string())
+            .join(format!("day{day:02}.txt"))
+    }
+
+    /// Returns the path of the cached input, fetching it first if it is missing
+    pub fn cached_file(&self, year: u16, day: u8) -> InputResult<PathBuf> {
+        let path = self.path(year, day);
+        if path.is_file() {
+            return Ok(path);
+        }
+        let Some(source) = &self.source else {
+            return Err(InputError::NotFound {
+                what: format!("cached input of {year} day {day} (no source to fetch it)"),
+                tried: vec![path],
+            });
+        };
+        let content = source.fetch(year, day)?;
+        let io_error = |source| InputError::Io {
+            file: path.to_string_lossy().to_string(),
+            line: None,
+            source,
+        };
+        fs::create_dir_all(path.parent().unwrap_or(&self.folder)).map_err(io_error)?;
+        // write to a temporary file first, an interrupted download must not look cached
+        let partial = path.with_extension("part");
+        fs::write(&partial, content).map_err(io_error)?;
+        fs::rename(&partial, &path).map_err(io_error)?;
+        Ok(path)
+    }
+}
+
+impl InputProvider for FileCache {
+    fn fetch(&self, year: u16, day: u8) -> InputResult<String> {
+        let path = self.cached_file(year, day)?;
+        crate::try_file_to_string(&path.to_string_lossy())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////
+
+/// Downloads the inputs from adventofcode.com (or a compatible server) with a session token
+#[cfg(feature = "http")]
+pub struct HttpProvider {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+#[cfg(feature = "http")]
+impl HttpProvider {
+    /// Provider for adventofcode.com with given session token
+    pub fn new(session: impl Into<String>) -> Self {
+        HttpProvider {
+            base_url: "https://adventofcode.com".to_string(),
+            session: session.into().trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(std::time::Duration::from_secs(30))
+                .user_agent("github.com/oechslein/AdventOfCode2022 (input cache)")
+                .build(),
+        }
+    }
+
+    /// Provider with the session token from `AOC_SESSION` (None if it is not set)
+    pub fn from_env() -> Option<Self> {
+        env::var(SESSION_ENV).ok().map(Self::new)
+    }
+
+    /// Uses another server (e.g. a local mock server in tests)
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// URL of the input of a day
+    pub fn url(&self, year: u16, day: u8) -> String {
+        format!("{}/{year}/day/{day}/input", self.base_url)
+    }
+}
+
+#[cfg(feature = "http")]
+impl InputProvider for HttpProvider {
+    fn fetch(&self, year: u16, day: u8) -> InputResult<String> {
+        let url = self.url(year, day);
+        let download_error = |message: String| InputError::Download {
+            url: url.clone(),
+            message,
+        };
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|error| match error {
+                ureq::Error::Status(status, _) => download_error(format!(
+                    "HTTP status {status} (is the session token in {SESSION_ENV} valid?)"
+                )),
+                ureq::Error::Transport(transport) => download_error(
+                    transport
+                        .message()
+                        .map_or_else(|| transport.kind().to_string(), ToString::to_string),
+                ),
+            })?;
+        response
+            .into_string()
+            .map_err(|error| download_error(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Provider that returns a fixed content and counts the calls
+    struct Fixed(std::rc::Rc<std::cell::Cell<usize>>);
+
+    impl InputProvider for Fixed {
+        fn fetch(&self, year: u16, day: u8) -> InputResult<String> {
+            self.0.set(self.0.get() + 1);
+            Ok(format!("{year} {day}\n"))
+        }
+    }
+
+    fn temp_folder(name: &str) -> PathBuf {
+        let folder = env::temp_dir().join(format!("utils_provider_{name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&folder);
+        folder
+    }
+
+    #[test]
+    fn offline_day_folders() {
+        let resolver = InputResolver::from_env();
+        assert!(resolver.fetch(YEAR, 1).unwrap().starts_with("5118\n"));
+        assert!(resolver.fetch(2021, 1).is_err());
+    }
+
+    #[test]
+    fn cache_fetches_once() {
+        let folder = temp_folder("cache");
+        let calls = std::rc::Rc::default();
+        let cache = FileCache::new(&folder).with_source(Fixed(std::rc::Rc::clone(&calls)));
+        assert_eq!(cache.fetch(2022, 5).unwrap(), "2022 5\n");
+        assert_eq!(cache.fetch(2022, 5).unwrap(), "2022 5\n");
+        assert_eq!(calls.get(), 1);
+        assert_eq!(cache.path(2022, 5), folder.join("2022").join("day05.txt"));
+
+        let offline = FileCache::new(&folder);
+        assert_eq!(offline.fetch(2022, 5).unwrap(), "2022 5\n");
+        assert!(offline.fetch(2022, 6).is_err());
+        fs::remove_dir_all(folder).unwrap();
+    }
+
+    #[cfg(feature = "http")]
+    mod http {
+        use super::*;
+        use std::io::{BufRead, BufReader, Write};
+        use std::net::TcpListener;
+
+        /// Serves one request: answers with the input if the session cookie matches, else 400
+        fn mock_server(session: &'static str) -> (String, std::thread::JoinHandle<String>) {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let base_url = format!("http://{}", listener.local_addr().unwrap());
+            let handle = std::thread::spawn(move || {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = Vec::new();
+                for line in BufReader::new(&stream).lines() {
+                    let line = line.unwrap();
+                    if line.is_empty() {
+                        break;
+                    }
+                    request.push(line);
+                }
+                let authorized = request
+                    .iter()
+                    .any(|line| line.eq_ignore_ascii_case(&format!("cookie: session={session}")));
+                let response = if authorized {
+                    "HTTP/1.1 200 OK\r\nContent-Length: 6\r\nConnection: close\r\n\r\n1\n2\n3\n"
+                } else {
+                    "HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                };
+                stream.write_all(response.as_bytes()).unwrap();
+                request[0].clone()
+            });
+            (base_url, handle)
+        }
+
+        #[test]
+        fn download() {
+            let (base_url, server) = mock_server("secret");
+            let provider = HttpProvider::new("secret\n").with_base_url(&base_url);
+            assert_eq!(provider.fetch(2022, 7).unwrap(), "1\n2\n3\n");
+            assert_eq!(server.join().unwrap(), "GET /2022/day/7/input HTTP/1.1");
+        }
+
+        #[test]
+        fn invalid_session() {
+            let (base_url, server) = mock_server("secret");
+            let provider = HttpProvider::new("wrong").with_base_url(&base_url);
+            let error = provider.fetch(2022, 7).unwrap_err().to_string();
+            server.join().unwrap();
+            assert_eq!(
+                error,
+                format!("{base_url}/2022/day/7/input: HTTP status 400 (is the session token in AOC_SESSION valid?)")
+            );
+        }
+    }
+}
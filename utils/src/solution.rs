@@ -0,0 +1,106 @@
+//! Common interface of all days
+
+use std::fmt::Display;
+
+use crate::{bench, InputFile, InputResult};
+
+/// Answer of a puzzle part
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// numeric answer (wide enough for all signed and unsigned results)
+    Int(i128),
+    /// textual answer (e.g. crate letters or rendered CRT output)
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($convert:expr => $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Int($convert(value))
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i128::from => u8, u16, u32, u64, i8, i16, i32, i64);
+answer_from_int!(|value| i128::try_from(value).unwrap() => usize, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Kind of input given to a solver, selects the matching parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputKind {
+    /// example from the puzzle description (test.txt)
+    Example,
+    /// real puzzle input (input.txt)
+    Puzzle,
+}
+
+/// Common interface of all days
+pub trait Solution {
+    /// Day of the puzzle (1..=25)
+    const DAY: u8;
+
+    /// Extra parameters which are not part of the input file (e.g. the row to inspect on day 15)
+    type Params: Default;
+
+    /// Returns the parameters for given input kind (default: `Params::default()` for all kinds)
+    fn params(_kind: InputKind) -> Self::Params {
+        Self::Params::default()
+    }
+
+    /// Solves part 1
+    fn part1(file_name: &str, params: &Self::Params) -> InputResult<Answer>;
+
+    /// Solves part 2
+    fn part2(file_name: &str, params: &Self::Params) -> InputResult<Answer>;
+
+    /// Runs both parts on an input of the day and prints the answers with their times
+    /// (the `main` of the day crates, see [`bench::time`])
+    fn run(file: &InputFile) -> InputResult<()> {
+        let input = crate::input_file(Self::DAY, file)?;
+        let params = Self::params(file.kind());
+        let (answer, timing) = bench::time(|| Self::part1(&input, &params));
+        println!("Part 1 result: {} ({timing})", answer?);
+        let (answer, timing) = bench::time(|| Self::part2(&input, &params));
+        println!("Part 2 result: {} ({timing})", answer?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answer_conversions() {
+        assert_eq!(Answer::from(42usize), Answer::Int(42));
+        assert_eq!(Answer::from(-42isize), Answer::Int(-42));
+        assert_eq!(Answer::from(7u16), Answer::from(7u32));
+        assert_eq!(Answer::from("CMZ"), Answer::Text("CMZ".to_string()));
+        assert_eq!(Answer::from(-3i64).to_string(), "-3");
+        assert_eq!(Answer::from("MCD").to_string(), "MCD");
+    }
+}
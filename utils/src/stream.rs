@@ -0,0 +1,175 @@
+//! Streaming reading of inputs: lines and blank line separated chunks are split lazily from a
+//! buffered reader (CRLF is normalised), so inputs larger than memory can be processed
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+
+use crate::{correct_folder, InputError, InputResult};
+
+/// Buffered reader returning lines or chunks without loading the whole input,
+/// the returned str borrows an internal buffer that is reused for the next call
+pub struct InputReader<R> {
+    reader: R,
+    file_name: String,
+    line_number: usize,
+    line: String,
+    chunk: String,
+}
+
+impl InputReader<BufReader<File>> {
+    /// Opens a file (see [`correct_folder`])
+    pub fn open(file_name: &str) -> InputResult<Self> {
+        let file = File::open(correct_folder(file_name)).map_err(|source| InputError::Io {
+            file: file_name.to_string(),
+            line: None,
+            source,
+        })?;
+        Ok(Self::new(BufReader::new(file), file_name))
+    }
+}
+
+impl<R: BufRead> InputReader<R> {
+    /// Reads from any buffered reader, `file_name` is only used in errors
+    pub fn new(reader: R, file_name: &str) -> Self {
+        InputReader {
+            reader,
+            file_name: file_name.to_string(),
+            line_number: 0,
+            line: String::new(),
+            chunk: String::new(),
+        }
+    }
+
+    /// Number (1-based) of the last read line, 0 before the first line
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+
+    /// Returns the next line without "\n" / "\r\n", None at the end of the input
+    pub fn next_line(&mut self) -> InputResult<Option<&str>> {
+        if self.read_line()? {
+            Ok(Some(self.line.as_str()))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns the next chunk of lines up to an empty line (lines joined with "\n"),
+    /// repeated empty lines do not produce empty chunks, None at the end of the input
+    pub fn next_chunk(&mut self) -> InputResult<Option<&str>> {
+        self.chunk.clear();
+        while self.read_line()? {
+            let line = self.line.as_str();
+            if line.is_empty() {
+                if self.chunk.is_empty() {
+                    continue;
+                }
+                break;
+            }
+            if !self.chunk.is_empty() {
+                self.chunk.push('\n');
+            }
+            self.chunk.push_str(line);
+        }
+        Ok((!self.chunk.is_empty()).then_some(self.chunk.as_str()))
+    }
+
+    /// Iterator over the (owned) lines
+    pub fn lines(mut self) -> impl Iterator<Item = InputResult<String>> {
+        std::iter::from_fn(move || {
+            self.next_line()
+                .map(|line| line.map(str::to_string))
+                .transpose()
+        })
+    }
+
+    /// Iterator over the (owned) chunks, see [`InputReader::next_chunk`]
+    pub fn chunks(mut self) -> impl Iterator<Item = InputResult<String>> {
+        std::iter::from_fn(move || {
+            self.next_chunk()
+                .map(|chunk| chunk.map(str::to_string))
+                .transpose()
+        })
+    }
+
+    /// Reads the next line into the buffer (line ending removed and checked to be UTF-8),
+    /// returns false at the end of the input
+    fn read_line(&mut self) -> InputResult<bool> {
+        // the bytes are read into the allocation of the last line and checked only once
+        let mut bytes = std::mem::take(&mut self.line).into_bytes();
+        bytes.clear();
+        let io_error = |reader: &Self, source| InputError::Io {
+            file: reader.file_name.clone(),
+            line: Some(reader.line_number + 1),
+            source,
+        };
+        if self
+            .reader
+            .read_until(b'\n', &mut bytes)
+            .map_err(|source| io_error(self, source))?
+            == 0
+        {
+            return Ok(false);
+        }
+        if bytes.last() == Some(&b'\n') {
+            bytes.pop();
+        }
+        if bytes.last() == Some(&b'\r') {
+            bytes.pop();
+        }
+        self.line = String::from_utf8(bytes).map_err(|error| {
+            io_error(
+                self,
+                io::Error::new(io::ErrorKind::InvalidData, error.utf8_error()),
+            )
+        })?;
+        self.line_number += 1;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn reader(content: &'static [u8]) -> InputReader<Cursor<&'static [u8]>> {
+        InputReader::new(Cursor::new(content), "test.txt")
+    }
+
+    #[test]
+    fn lines() {
+        let mut input = reader(b"a\r\nb\n\r\nc");
+        assert_eq!(input.next_line().unwrap(), Some("a"));
+        assert_eq!(input.next_line().unwrap(), Some("b"));
+        assert_eq!(input.next_line().unwrap(), Some(""));
+        assert_eq!(input.next_line().unwrap(), Some("c"));
+        assert_eq!(input.line_number(), 4);
+        assert_eq!(input.next_line().unwrap(), None);
+        assert_eq!(
+            reader(b"1\n2\n")
+                .lines()
+                .collect::<InputResult<Vec<_>>>()
+                .unwrap(),
+            ["1", "2"]
+        );
+    }
+
+    #[test]
+    fn chunks() {
+        let chunks = reader(b"\r\n1\r\n2\r\n\r\n\r\n3\r\n\r\n4")
+            .chunks()
+            .collect::<InputResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(chunks, ["1\n2", "3", "4"]);
+        assert_eq!(reader(b"\n\n").chunks().count(), 0);
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let mut input = reader(b"ok\n\xff\n");
+        assert_eq!(input.next_line().unwrap(), Some("ok"));
+        let error = input.next_line().unwrap_err().to_string();
+        assert!(error.starts_with("test.txt:2: "), "{error}");
+    }
+}